@@ -9,9 +9,19 @@ use syn::{parse_macro_input, DeriveInput, Field, Path, Type};
 
 #[derive(Debug, FromMeta)]
 struct SensorAttributes {
-    id: u8,
-    data: Path,
-    components: u8,
+    id: Option<u8>,
+    data: Option<Path>,
+    components: Option<u8>,
+    /// Marks the variant that captures vendor-specific sensor types.
+    ///
+    /// The variant must have the named fields `type_id`, `value_type` and `payload`, and the
+    /// enum must provide the reserved type IDs as a `VENDOR_TYPE_IDS` range constant.
+    #[darling(default)]
+    vendor: bool,
+    /// Skips the `CompileTimeTypeInformation` implementation, e.g. for types that are
+    /// defined in another crate and already implement it.
+    #[darling(default)]
+    skip_type_info: bool,
 }
 
 #[derive(Debug, FromVariant)]
@@ -24,41 +34,130 @@ struct Version1DataVariant {
 }
 
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(sensor), supports(enum_newtype))]
+#[darling(attributes(sensor), supports(enum_newtype, enum_named))]
 struct Version1Data {
     ident: syn::Ident,
     data: darling::ast::Data<Version1DataVariant, darling::util::Ignored>,
+    /// The path to the `serial_sensors_proto` crate.
+    #[darling(rename = "crate")]
+    krate: Option<Path>,
 }
 
+/// Returns the path to the `serial_sensors_proto` crate, or the specified override.
+fn crate_path(krate: Option<&Path>) -> proc_macro2::TokenStream {
+    krate.map_or_else(
+        || quote! { ::serial_sensors_proto },
+        |path| quote! { #path },
+    )
+}
+
+/// Derives the sensor type information and wire format for an enum of sensor types.
+///
+/// Each variant requires a `#[sensor(id = .., data = .., components = ..)]` attribute;
+/// `skip_type_info` omits the `CompileTimeTypeInformation` implementation for the variant's type.
+/// A single variant with the named fields `type_id`, `value_type` and `payload` can be marked
+/// with `#[sensor(vendor)]` to capture the sensor types whose IDs are in the enum's
+/// `VENDOR_TYPE_IDS` range constant; other unknown sensor types fail to decode, and vendor
+/// readings with other type IDs fail to encode.
+/// The path to the `serial_sensors_proto` crate can be overridden with `#[sensor(crate = ..)]`.
+/// The types of all variants need to implement `MaxEncodedLen`; the largest serialized length
+/// is provided as `MAX_ENCODED_LEN`. They also need to implement `Components`, which provides
+/// the numeric components of a reading; vendor readings have none.
 #[proc_macro_derive(SerialSensors, attributes(sensor))]
 pub fn derive_serial_sensors(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let version1_data = Version1Data::from_derive_input(&input).expect("Failed to parse input");
 
     let name = &version1_data.ident;
+    let krate = crate_path(version1_data.krate.as_ref());
 
     let mut sensor_match_arms = Vec::new();
     let mut field_match_arms = Vec::new();
     let mut num_components_match_arms = Vec::new();
     let mut from_impls = Vec::new();
     let mut encode_match_arms = Vec::new();
+    let mut component_match_arms = Vec::new();
     let mut decode_match_arms = Vec::new();
     let mut components_lookup_match_arms = Vec::new();
     let mut sensor_ids_variants = Vec::new();
+    let mut max_encoded_len_checks = Vec::new();
 
     let mut sensor_types = HashSet::new();
     let mut duplicate_error = None;
+    let mut unknown_type_arm = quote! {
+        _ => Err(#krate::__private::bincode::error::DecodeError::Other("An unknown combination of type ID and value type was detected"))
+    };
 
     if let darling::ast::Data::Enum(variants) = &version1_data.data {
         for variant in variants {
             let variant_name = &variant.ident;
-            let sensor_type = &variant.sensor.id;
-            let field_type = &variant.sensor.data;
-            let num_components = variant.sensor.components;
-
             let variant_name_str = variant_name.to_string();
 
-            if !sensor_types.insert(sensor_type) {
+            if variant.sensor.vendor {
+                sensor_match_arms.push(quote! {
+                    #name::#variant_name { type_id, .. } => *type_id,
+                });
+
+                field_match_arms.push(quote! {
+                    #name::#variant_name { value_type, .. } => *value_type,
+                });
+
+                num_components_match_arms.push(quote! {
+                    #name::#variant_name { .. } => 1,
+                });
+
+                component_match_arms.push(quote! {
+                    #name::#variant_name { .. } => core::option::Option::None,
+                });
+
+                encode_match_arms.push(quote! {
+                    #name::#variant_name { type_id, payload, .. } => {
+                        if !#name::VENDOR_TYPE_IDS.contains(type_id) {
+                            return Err(#krate::__private::bincode::error::EncodeError::Other("The vendor reading has no vendor type ID"));
+                        }
+                        #krate::__private::bincode::Encode::encode(payload, encoder)?
+                    }
+                });
+
+                if let Some(payload) = variant
+                    .fields
+                    .fields
+                    .iter()
+                    .find(|field| field.ident.as_ref().is_some_and(|ident| ident == "payload"))
+                {
+                    let payload_type = &payload.ty;
+                    max_encoded_len_checks.push(quote! {
+                        if <#payload_type as #krate::MaxEncodedLen>::MAX_ENCODED_LEN > max {
+                            max = <#payload_type as #krate::MaxEncodedLen>::MAX_ENCODED_LEN;
+                        }
+                    });
+                }
+
+                unknown_type_arm = quote! {
+                    (type_id, value_type) if #name::VENDOR_TYPE_IDS.contains(&type_id) => Ok(#name::#variant_name {
+                        type_id,
+                        value_type,
+                        payload: #krate::__private::bincode::Decode::decode(decoder)?,
+                    }),
+                    #unknown_type_arm
+                };
+                continue;
+            }
+
+            let (Some(sensor_type), Some(field_type), Some(num_components)) = (
+                &variant.sensor.id,
+                &variant.sensor.data,
+                variant.sensor.components,
+            ) else {
+                duplicate_error = Some(quote! {
+                    compile_error!(concat!("Missing sensor id, data or components at ", #variant_name_str));
+                });
+                break;
+            };
+
+            // Sensor types are identified by their type ID and value type.
+            let value_type_str = quote!(#field_type).to_string();
+            if !sensor_types.insert((*sensor_type, value_type_str)) {
                 duplicate_error = Some(quote! {
                     compile_error!(concat!("Duplicate sensor type found (", #sensor_type, ") at ", #variant_name_str));
                 });
@@ -68,23 +167,27 @@ pub fn derive_serial_sensors(input: TokenStream) -> TokenStream {
             // Extract the type of the variant's field
             let variant_field_type = &variant.fields.fields[0].ty;
 
-            from_impls.push(quote! {
-                impl crate::CompileTimeTypeInformation for #variant_field_type {
-                    const TYPE_ID: u8 = #sensor_type;
-                    const VALUE_TYPE: crate::ValueType = #field_type;
-                    const NUM_COMPONENTS: u8 = #num_components;
-                }
+            if !variant.sensor.skip_type_info {
+                from_impls.push(quote! {
+                    impl #krate::CompileTimeTypeInformation for #variant_field_type {
+                        const TYPE_ID: u8 = #sensor_type;
+                        const VALUE_TYPE: #krate::ValueType = #field_type;
+                        const NUM_COMPONENTS: u8 = #num_components;
+                    }
+                });
+            }
 
+            from_impls.push(quote! {
                 impl core::convert::From< #variant_field_type > for #name {
                     fn from(value: #variant_field_type) -> #name {
                         #name :: #variant_name ( value )
                     }
                 }
 
-                impl TryFrom< #name > for #variant_field_type {
+                impl core::convert::TryFrom< #name > for #variant_field_type {
                     type Error = ();
 
-                    fn try_from(value: #name) -> Result<#variant_field_type, Self::Error> {
+                    fn try_from(value: #name) -> core::result::Result<#variant_field_type, Self::Error> {
                         match value {
                             #name :: #variant_name (value) => Ok(value),
                             _ => Err(())
@@ -105,13 +208,17 @@ pub fn derive_serial_sensors(input: TokenStream) -> TokenStream {
                 #name::#variant_name(_) => #num_components,
             });
 
+            component_match_arms.push(quote! {
+                #name::#variant_name(value) => #krate::Components::component(value, index),
+            });
+
             encode_match_arms.push(quote! {
-                #name::#variant_name(value) => ::bincode::Encode::encode(&value, encoder)?,
+                #name::#variant_name(value) => #krate::__private::bincode::Encode::encode(value, encoder)?,
             });
 
             decode_match_arms.push(quote! {
                 (#sensor_type, #field_type) => {
-                    let value: #variant_field_type = ::bincode::Decode::decode(decoder)?;
+                    let value: #variant_field_type = #krate::__private::bincode::Decode::decode(decoder)?;
                     Ok(#name :: #variant_name ( value ))
                 }
             });
@@ -120,6 +227,12 @@ pub fn derive_serial_sensors(input: TokenStream) -> TokenStream {
                 (#sensor_type, #field_type) => Ok(#num_components),
             });
 
+            max_encoded_len_checks.push(quote! {
+                if <#variant_field_type as #krate::MaxEncodedLen>::MAX_ENCODED_LEN > max {
+                    max = <#variant_field_type as #krate::MaxEncodedLen>::MAX_ENCODED_LEN;
+                }
+            });
+
             let upper_variant = format_ident!("{}", variant_name.to_string().to_uppercase());
 
             let type_name_str = name.to_string();
@@ -128,7 +241,7 @@ pub fn derive_serial_sensors(input: TokenStream) -> TokenStream {
                 /// [`SensorId`] const for the
                 #[doc = #name ]
                 /// type.
-                pub const #upper_variant : SensorId = SensorId(0x00, #sensor_type, #field_type);
+                pub const #upper_variant : #krate::SensorId = #krate::SensorId::new_with(0x00, #sensor_type, #field_type);
             });
         }
     }
@@ -138,6 +251,14 @@ pub fn derive_serial_sensors(input: TokenStream) -> TokenStream {
     } else {
         quote! {
             impl #name {
+                /// The maximum number of serialized bytes, i.e. the type ID and value type
+                /// followed by the largest sensor reading.
+                pub const MAX_ENCODED_LEN: usize = {
+                    let mut max = 0;
+                    #( #max_encoded_len_checks )*
+                    2 + max
+                };
+
                 /// Provides the sensor type ID.
                 pub const fn sensor_type_id(&self) -> u8 {
                     match self {
@@ -146,7 +267,7 @@ pub fn derive_serial_sensors(input: TokenStream) -> TokenStream {
                 }
 
                 /// Provides the value type.
-                pub const fn value_type(&self) -> crate::ValueType {
+                pub const fn value_type(&self) -> #krate::ValueType {
                     match self {
                         #( #field_match_arms )*
                     }
@@ -159,23 +280,47 @@ pub fn derive_serial_sensors(input: TokenStream) -> TokenStream {
                     }
                 }
 
+                /// Encodes the sensor reading without its type ID and value type.
+                pub fn encode_value<__E: #krate::__private::bincode::enc::Encoder>(
+                    &self,
+                    encoder: &mut __E,
+                ) -> core::result::Result<(), #krate::__private::bincode::error::EncodeError> {
+                    match self {
+                        #( #encode_match_arms )*
+                    }
+                    Ok(())
+                }
+
+                /// Decodes a sensor reading of the specified type ID and value type, i.e.
+                /// a value that was encoded using `encode_value`.
+                pub fn decode_value<__D: #krate::__private::bincode::de::Decoder>(
+                    type_id: u8,
+                    value_type: #krate::ValueType,
+                    decoder: &mut __D,
+                ) -> core::result::Result<Self, #krate::__private::bincode::error::DecodeError> {
+                    match (type_id, value_type) {
+                        #( #decode_match_arms )*,
+                        #unknown_type_arm
+                    }
+                }
+
                 /// Provides the number of components of the data type.
-                pub const fn components(sensor_id: u8, value_type: crate::ValueType) -> Result<u8, crate::ComponentLookupError> {
+                pub const fn components(sensor_id: u8, value_type: #krate::ValueType) -> core::result::Result<u8, #krate::ComponentLookupError> {
                     match (sensor_id, value_type) {
                         #( #components_lookup_match_arms )*
-                        _ => Err(ComponentLookupError::UnknownType)
+                        _ => Err(#krate::ComponentLookupError::UnknownType)
                     }
                 }
             }
 
-            impl crate::RuntimeTypeInformation for #name {
+            impl #krate::RuntimeTypeInformation for #name {
                 fn sensor_type_id(&self) -> u8 {
                     match self {
                         #( #sensor_match_arms )*
                     }
                 }
 
-                fn value_type(&self) -> crate::ValueType {
+                fn value_type(&self) -> #krate::ValueType {
                     match self {
                         #( #field_match_arms )*
                     }
@@ -188,6 +333,18 @@ pub fn derive_serial_sensors(input: TokenStream) -> TokenStream {
                 }
             }
 
+            impl #krate::MaxEncodedLen for #name {
+                const MAX_ENCODED_LEN: usize = #name::MAX_ENCODED_LEN;
+            }
+
+            impl #krate::Components for #name {
+                fn component(&self, index: usize) -> core::option::Option<f32> {
+                    match self {
+                        #( #component_match_arms )*
+                    }
+                }
+            }
+
             /// Provides generic [`SensorId`] implementations.
             pub struct SensorIds;
 
@@ -197,33 +354,27 @@ pub fn derive_serial_sensors(input: TokenStream) -> TokenStream {
 
             #( #from_impls )*
 
-            impl ::bincode::Encode for #name {
-                fn encode<__E: ::bincode::enc::Encoder>(
+            impl #krate::__private::bincode::Encode for #name {
+                fn encode<__E: #krate::__private::bincode::enc::Encoder>(
                     &self,
                     encoder: &mut __E,
-                ) -> core::result::Result<(), ::bincode::error::EncodeError> {
-                    use crate::RuntimeTypeInformation;
-                    bincode::Encode::encode(&self.sensor_type_id(), encoder)?;
-                    bincode::Encode::encode(&(self.value_type() as u8), encoder)?;
+                ) -> core::result::Result<(), #krate::__private::bincode::error::EncodeError> {
+                    use #krate::RuntimeTypeInformation;
+                    #krate::__private::bincode::Encode::encode(&self.sensor_type_id(), encoder)?;
+                    #krate::__private::bincode::Encode::encode(&(self.value_type() as u8), encoder)?;
                     // don't encode the component count; sensor ID and type are enough
-                    match self {
-                        #( #encode_match_arms )*
-                    }
-                    Ok(())
+                    self.encode_value(encoder)
                 }
             }
 
-            impl ::bincode::Decode for #name {
-                fn decode<__D: bincode::de::Decoder>(
+            impl #krate::__private::bincode::Decode for #name {
+                fn decode<__D: #krate::__private::bincode::de::Decoder>(
                     decoder: &mut __D,
-                ) -> Result<Self, ::bincode::error::DecodeError> {
-                    let type_id: u8 = bincode::Decode::decode(decoder)?;
-                    let value_type: u8 = bincode::Decode::decode(decoder)?;
-                    let value_type = crate::ValueType::try_from(value_type).map_err(|_| ::bincode::error::DecodeError::Other("An unknown combination of type ID and value type was detected"))?;
-                    match (type_id, value_type) {
-                        #( #decode_match_arms )*,
-                        _ => Err(::bincode::error::DecodeError::Other("An unknown combination of type ID and value type was detected"))
-                    }
+                ) -> core::result::Result<Self, #krate::__private::bincode::error::DecodeError> {
+                    let type_id: u8 = #krate::__private::bincode::Decode::decode(decoder)?;
+                    let value_type: u8 = #krate::__private::bincode::Decode::decode(decoder)?;
+                    let value_type = #krate::ValueType::try_from(value_type).map_err(|_| #krate::__private::bincode::error::DecodeError::Other("An unknown combination of type ID and value type was detected"))?;
+                    Self::decode_value(type_id, value_type, decoder)
                 }
             }
         }
@@ -237,18 +388,79 @@ pub fn derive_serial_sensors(input: TokenStream) -> TokenStream {
 struct SensorDataType {
     ident: syn::Ident,
     data: darling::ast::Data<darling::util::Ignored, Type>,
+    /// The path to the `serial_sensors_proto` crate.
+    #[darling(rename = "crate")]
+    krate: Option<Path>,
+    /// Skips the conversions from the crate's data frames, e.g. for types that
+    /// are not part of `SensorData`.
+    #[darling(default)]
+    skip_frame_conversions: bool,
 }
 
-#[proc_macro_derive(SensorDataType)]
+/// Derives constructors and conversions for a newtype sensor type.
+///
+/// The serialized length is provided as `ENCODED_LEN` and through `MaxEncodedLen`, which is
+/// forwarded from the wrapped type, as is `Components`.
+///
+/// `#[sensor(skip_frame_conversions)]` omits the conversions from the crate's data frames,
+/// and `#[sensor(crate = ..)]` overrides the path to the `serial_sensors_proto` crate.
+#[proc_macro_derive(SensorDataType, attributes(sensor))]
 pub fn derive_sensor_data_type(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let data = SensorDataType::from_derive_input(&input).expect("Failed to parse input");
 
     let name = &data.ident;
+    let krate = crate_path(data.krate.as_ref());
     let expanded = if let darling::ast::Data::Struct(fields) = &data.data {
         let field = &fields.fields[0];
+
+        let frame_conversions = if data.skip_frame_conversions {
+            quote! {}
+        } else {
+            quote! {
+                impl core::convert::TryFrom<#krate::versions::Version1DataFrame> for #name {
+                    type Error = ();
+
+                    #[inline]
+                    fn try_from(value: #krate::versions::Version1DataFrame) -> core::result::Result<Self, Self::Error> {
+                        core::convert::TryInto::try_into(value.value)
+                    }
+                }
+
+                impl core::convert::TryFrom<#krate::VersionedDataFrame<#krate::versions::Version1, #krate::versions::Version1DataFrame>> for #name {
+                    type Error = ();
+
+                    #[inline]
+                    fn try_from(value: #krate::VersionedDataFrame<#krate::versions::Version1, #krate::versions::Version1DataFrame>) -> core::result::Result<Self, Self::Error> {
+                        core::convert::TryInto::try_into(value.data.value)
+                    }
+                }
+
+                impl core::convert::TryFrom<#krate::versions::Version2DataFrame> for #name {
+                    type Error = ();
+
+                    #[inline]
+                    fn try_from(value: #krate::versions::Version2DataFrame) -> core::result::Result<Self, Self::Error> {
+                        core::convert::TryInto::try_into(value.value)
+                    }
+                }
+
+                impl core::convert::TryFrom<#krate::VersionedDataFrame<#krate::versions::Version2, #krate::versions::Version2DataFrame>> for #name {
+                    type Error = ();
+
+                    #[inline]
+                    fn try_from(value: #krate::VersionedDataFrame<#krate::versions::Version2, #krate::versions::Version2DataFrame>) -> core::result::Result<Self, Self::Error> {
+                        core::convert::TryInto::try_into(value.data.value)
+                    }
+                }
+            }
+        };
+
         quote! {
             impl #name {
+                /// The number of serialized bytes of a reading, excluding its type information.
+                pub const ENCODED_LEN: usize = <#field as #krate::MaxEncodedLen>::MAX_ENCODED_LEN;
+
                 /// Constructs a new instance of the [`#name`] type.
                 #[must_use]
                 pub const fn new(value: #field) -> Self {
@@ -268,6 +480,17 @@ pub fn derive_sensor_data_type(input: TokenStream) -> TokenStream {
                 }
             }
 
+            impl #krate::MaxEncodedLen for #name {
+                const MAX_ENCODED_LEN: usize = #name::ENCODED_LEN;
+            }
+
+            impl #krate::Components for #name {
+                #[inline]
+                fn component(&self, index: usize) -> core::option::Option<f32> {
+                    #krate::Components::component(&self.0, index)
+                }
+            }
+
             impl core::convert::AsRef<#field> for #name {
                 fn as_ref(&self) -> &#field {
                     &self.0
@@ -294,35 +517,19 @@ pub fn derive_sensor_data_type(input: TokenStream) -> TokenStream {
                 }
             }
 
-            impl From<#name> for #field {
+            impl core::convert::From<#name> for #field {
                 fn from(value: #name) -> #field {
                     value.0
                 }
             }
 
-            impl From<#field> for #name {
+            impl core::convert::From<#field> for #name {
                 fn from(value: #field) -> #name {
                     #name(value)
                 }
             }
 
-            impl TryFrom<crate::versions::Version1DataFrame> for #name {
-                type Error = ();
-
-                #[inline]
-                fn try_from(value: crate::versions::Version1DataFrame) -> Result<Self, Self::Error> {
-                    value.value.try_into()
-                }
-            }
-
-            impl TryFrom<crate::VersionedDataFrame<crate::versions::Version1, crate::versions::Version1DataFrame>> for #name {
-                type Error = ();
-
-                #[inline]
-                fn try_from(value: crate::VersionedDataFrame<crate::versions::Version1, crate::versions::Version1DataFrame>) -> Result<Self, Self::Error> {
-                    value.data.value.try_into()
-                }
-            }
+            #frame_conversions
         }
     } else {
         quote! {}
@@ -0,0 +1,232 @@
+/// Flag in the version byte indicating a CRC-16 trailer.
+pub(crate) const FLAG_CRC16: u8 = 0x80;
+
+/// Flag in the version byte indicating a CRC-32 trailer.
+pub(crate) const FLAG_CRC32: u8 = 0x40;
+
+/// Mask of all capability flags in the version byte.
+pub(crate) const FLAGS_MASK: u8 = FLAG_CRC16 | FLAG_CRC32;
+
+/// Frame integrity check appended to the serialized data frame.
+///
+/// If a checksum is used, the corresponding capability flag is set in the
+/// version byte of the frame, so that receivers can tell whether (and which) trailer
+/// is present. Peers that are unaware of the flags reject such frames as having an
+/// invalid version.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Checksum {
+    /// No integrity check.
+    #[default]
+    None,
+    /// A CRC-16/CCITT-FALSE trailer (polynomial `0x1021`, initial value `0xFFFF`), 2 bytes.
+    Crc16,
+    /// A CRC-32/ISO-HDLC trailer (polynomial `0x04C11DB7`, reflected), 4 bytes.
+    Crc32,
+}
+
+impl Checksum {
+    /// Returns the number of bytes of the trailer.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        match self {
+            Checksum::None => 0,
+            Checksum::Crc16 => 2,
+            Checksum::Crc32 => 4,
+        }
+    }
+
+    /// Indicates whether this checksum adds no trailer.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the version byte flag for this checksum.
+    pub(crate) const fn flag(self) -> u8 {
+        match self {
+            Checksum::None => 0,
+            Checksum::Crc16 => FLAG_CRC16,
+            Checksum::Crc32 => FLAG_CRC32,
+        }
+    }
+
+    /// Determines the checksum from the version byte flags.
+    pub(crate) const fn from_flags(version: u8) -> Option<Self> {
+        match version & FLAGS_MASK {
+            0 => Some(Checksum::None),
+            FLAG_CRC16 => Some(Checksum::Crc16),
+            FLAG_CRC32 => Some(Checksum::Crc32),
+            _ => None,
+        }
+    }
+
+    /// Writes the checksum of `data` into `trailer` in little-endian order.
+    ///
+    /// The `trailer` must be exactly [`len`](Self::len) bytes long.
+    pub(crate) fn write(self, data: &[u8], trailer: &mut [u8]) {
+        match self {
+            Checksum::None => {}
+            Checksum::Crc16 => trailer.copy_from_slice(&crc16(data).to_le_bytes()),
+            Checksum::Crc32 => trailer.copy_from_slice(&crc32(data).to_le_bytes()),
+        }
+    }
+
+    /// Verifies the checksum of `data` against the little-endian `trailer`.
+    pub(crate) fn verify(self, data: &[u8], trailer: &[u8]) -> bool {
+        match self {
+            Checksum::None => true,
+            Checksum::Crc16 => crc16(data).to_le_bytes() == trailer,
+            Checksum::Crc32 => crc32(data).to_le_bytes() == trailer,
+        }
+    }
+}
+
+/// Lookup table for CRC-16/CCITT-FALSE.
+const CRC16_TABLE: [u16; 256] = {
+    let mut table = [0_u16; 256];
+    let mut i = 0;
+    while i < 256 {
+        #[allow(clippy::cast_possible_truncation)]
+        let mut crc = (i as u16) << 8;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Lookup table for CRC-32/ISO-HDLC.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0_u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        #[allow(clippy::cast_possible_truncation)]
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Calculates the CRC-16/CCITT-FALSE checksum of the data.
+fn crc16(data: &[u8]) -> u16 {
+    crc16_update(0xFFFF, data)
+}
+
+/// Feeds data into a running CRC-16/CCITT-FALSE calculation.
+fn crc16_update(crc: u16, data: &[u8]) -> u16 {
+    data.iter().fold(crc, |crc, &byte| {
+        (crc << 8) ^ CRC16_TABLE[usize::from((crc >> 8) as u8 ^ byte)]
+    })
+}
+
+/// Calculates the CRC-32/ISO-HDLC checksum of the data.
+fn crc32(data: &[u8]) -> u32 {
+    !crc32_update(0xFFFF_FFFF, data)
+}
+
+/// Feeds data into a running CRC-32/ISO-HDLC calculation; the result is not yet inverted.
+fn crc32_update(crc: u32, data: &[u8]) -> u32 {
+    data.iter().fold(crc, |crc, &byte| {
+        (crc >> 8) ^ CRC32_TABLE[usize::from(crc.to_le_bytes()[0] ^ byte)]
+    })
+}
+
+/// A checksum calculation over data that arrives in pieces.
+#[cfg(any(feature = "embedded-io", feature = "std"))]
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Digest {
+    checksum: Checksum,
+    state: u32,
+}
+
+#[cfg(any(feature = "embedded-io", feature = "std"))]
+impl Digest {
+    /// Starts a new calculation of the specified checksum.
+    pub(crate) const fn new(checksum: Checksum) -> Self {
+        let state = match checksum {
+            Checksum::None => 0,
+            Checksum::Crc16 => 0xFFFF,
+            Checksum::Crc32 => 0xFFFF_FFFF,
+        };
+        Self { checksum, state }
+    }
+
+    /// Returns the number of bytes of the trailer.
+    pub(crate) const fn len(self) -> usize {
+        self.checksum.len()
+    }
+
+    /// Feeds data into the calculation.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        self.state = match self.checksum {
+            Checksum::None => 0,
+            Checksum::Crc16 => u32::from(crc16_update(self.state as u16, data)),
+            Checksum::Crc32 => crc32_update(self.state, data),
+        };
+    }
+
+    /// Returns the little-endian trailer; only the first [`Checksum::len`] bytes are used.
+    pub(crate) fn finalize(self) -> [u8; 4] {
+        match self.checksum {
+            Checksum::Crc32 => (!self.state).to_le_bytes(),
+            Checksum::None | Checksum::Crc16 => self.state.to_le_bytes(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_values() {
+        // Standard check values for the input "123456789".
+        assert_eq!(crc16(b"123456789"), 0x29B1);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_flags() {
+        for checksum in [Checksum::None, Checksum::Crc16, Checksum::Crc32] {
+            assert_eq!(Checksum::from_flags(0x01 | checksum.flag()), Some(checksum));
+        }
+        assert_eq!(Checksum::from_flags(0x01 | FLAGS_MASK), None);
+    }
+
+    #[test]
+    #[cfg(any(feature = "embedded-io", feature = "std"))]
+    fn test_digest() {
+        for checksum in [Checksum::None, Checksum::Crc16, Checksum::Crc32] {
+            let mut digest = Digest::new(checksum);
+            digest.update(b"1234");
+            digest.update(b"56789");
+
+            let mut trailer = [0_u8; 4];
+            checksum.write(b"123456789", &mut trailer[..checksum.len()]);
+            assert_eq!(
+                digest.finalize()[..checksum.len()],
+                trailer[..checksum.len()]
+            );
+        }
+    }
+}
@@ -0,0 +1,389 @@
+use crate::checksum::Digest;
+use crate::serializer::SERIALIZATION_CONFIG;
+use crate::{
+    Checksum, DataFrame, IoError, ProtocolVersion, SerializationError, VersionedDataFrame,
+};
+use bincode::enc::write::Writer;
+use bincode::enc::EncoderImpl;
+use bincode::error::EncodeError;
+use bincode::Encode;
+
+/// The maximum number of data bytes in a COBS block.
+const MAX_BLOCK_LEN: usize = 254;
+
+/// Serializes data and streams the byte-stuffed frame, including the trailing delimiter,
+/// into an [`embedded_io::Write`] sink.
+///
+/// Unlike [`serialize_with_checksum`](crate::serialize_with_checksum), no buffer for the
+/// whole frame is required: the encoded bytes are forwarded in blocks of at most 255 bytes.
+/// Use [`max_wire_len`](crate::max_wire_len) to determine the largest number of bytes written
+/// for a frame type. Returns the number of bytes written.
+///
+/// ## Errors
+/// The function returns an error when serialization failed or the sink reported an error.
+/// A frame that failed to serialize after parts of it were written is terminated with the
+/// delimiter; after a sink error, a partial frame may have been written. Receivers discard
+/// either at the next delimiter.
+#[cfg(feature = "embedded-io")]
+#[cfg_attr(docsrs, doc(cfg(feature = "embedded-io")))]
+pub fn serialize_to_writer<I, V, D, W>(
+    frame: I,
+    checksum: Checksum,
+    writer: &mut W,
+) -> Result<usize, SerializationError>
+where
+    I: Into<VersionedDataFrame<V, D>>,
+    V: ProtocolVersion,
+    D: DataFrame + Encode,
+    W: embedded_io::Write,
+{
+    encode_into_sink(&frame.into(), checksum, EmbeddedIoSink(writer))
+}
+
+/// Serializes data and streams the byte-stuffed frame, including the trailing delimiter,
+/// into a [`std::io::Write`] sink.
+///
+/// No buffer for the whole frame is required: the encoded bytes are forwarded in blocks of at
+/// most 255 bytes. Returns the number of bytes written.
+///
+/// ## Errors
+/// The function returns an error when serialization failed or the sink reported an error.
+/// A frame that failed to serialize after parts of it were written is terminated with the
+/// delimiter; after a sink error, a partial frame may have been written. Receivers discard
+/// either at the next delimiter.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn serialize_to_std_writer<I, V, D, W>(
+    frame: I,
+    checksum: Checksum,
+    writer: &mut W,
+) -> Result<usize, SerializationError>
+where
+    I: Into<VersionedDataFrame<V, D>>,
+    V: ProtocolVersion,
+    D: DataFrame + Encode,
+    W: std::io::Write,
+{
+    encode_into_sink(&frame.into(), checksum, StdIoSink(writer))
+}
+
+/// Encodes the frame through a [`CobsWriter`].
+fn encode_into_sink<V, D, S>(
+    frame: &VersionedDataFrame<V, D>,
+    checksum: Checksum,
+    sink: S,
+) -> Result<usize, SerializationError>
+where
+    V: ProtocolVersion,
+    D: DataFrame + Encode,
+    S: Sink,
+{
+    let mut encoder = EncoderImpl::new(CobsWriter::new(sink, checksum), SERIALIZATION_CONFIG);
+    let result = frame.encode(&mut encoder);
+
+    let writer = encoder.into_writer();
+    if let Some(err) = writer.error {
+        return Err(SerializationError::Io(err));
+    }
+
+    if let Err(err) = result {
+        writer.abort().map_err(SerializationError::Io)?;
+        return Err(err.into());
+    }
+    writer.finish().map_err(SerializationError::Io)
+}
+
+/// A destination for byte-stuffed data.
+trait Sink {
+    /// Writes all bytes to the destination.
+    fn write_all(&mut self, data: &[u8]) -> Result<(), IoError>;
+}
+
+/// Adapts an [`embedded_io::Write`] implementation.
+#[cfg(feature = "embedded-io")]
+struct EmbeddedIoSink<'a, W>(&'a mut W);
+
+#[cfg(feature = "embedded-io")]
+impl<W> Sink for EmbeddedIoSink<'_, W>
+where
+    W: embedded_io::Write,
+{
+    fn write_all(&mut self, mut data: &[u8]) -> Result<(), IoError> {
+        // Unlike `embedded_io::Write::write_all`, this does not panic if nothing was written.
+        while !data.is_empty() {
+            match self.0.write(data) {
+                Ok(0) => return Err(embedded_io::ErrorKind::WriteZero.into()),
+                Ok(count) => data = &data[count..],
+                Err(err) => return Err(embedded_io::Error::kind(&err).into()),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Adapts a [`std::io::Write`] implementation.
+#[cfg(feature = "std")]
+struct StdIoSink<'a, W>(&'a mut W);
+
+#[cfg(feature = "std")]
+impl<W> Sink for StdIoSink<'_, W>
+where
+    W: std::io::Write,
+{
+    fn write_all(&mut self, data: &[u8]) -> Result<(), IoError> {
+        self.0.write_all(data).map_err(IoError::Std)
+    }
+}
+
+/// Calculates the checksum of and applies byte stuffing to the data written by the encoder,
+/// forwarding every completed block to the sink.
+struct CobsWriter<S> {
+    sink: S,
+    /// The checksum calculation.
+    digest: Digest,
+    /// The checksum flags that still need to be applied to the version byte.
+    flags: u8,
+    /// The current block; the first byte is reserved for the block's code.
+    block: [u8; MAX_BLOCK_LEN + 1],
+    /// The number of data bytes in the current block.
+    len: usize,
+    /// Indicates whether the previous block was a full block without implicit zero.
+    maximal: bool,
+    /// The number of bytes written to the sink.
+    written: usize,
+    /// The first error reported by the sink.
+    error: Option<IoError>,
+}
+
+impl<S> CobsWriter<S>
+where
+    S: Sink,
+{
+    fn new(sink: S, checksum: Checksum) -> Self {
+        Self {
+            sink,
+            digest: Digest::new(checksum),
+            flags: checksum.flag(),
+            block: [0; MAX_BLOCK_LEN + 1],
+            len: 0,
+            maximal: false,
+            written: 0,
+            error: None,
+        }
+    }
+
+    /// Appends a byte to the current block.
+    fn push(&mut self, byte: u8) -> Result<(), IoError> {
+        if byte == 0 {
+            return self.flush_block(false);
+        }
+
+        self.len += 1;
+        self.block[self.len] = byte;
+        if self.len == MAX_BLOCK_LEN {
+            self.flush_block(true)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the current block to the sink.
+    #[allow(clippy::cast_possible_truncation)]
+    fn flush_block(&mut self, maximal: bool) -> Result<(), IoError> {
+        self.block[0] = (self.len + 1) as u8;
+        self.sink.write_all(&self.block[..=self.len])?;
+        self.written += self.len + 1;
+        self.len = 0;
+        self.maximal = maximal;
+        Ok(())
+    }
+
+    /// Appends the checksum trailer, terminates the frame and returns the number of
+    /// bytes written.
+    fn finish(mut self) -> Result<usize, IoError> {
+        let trailer = self.digest.finalize();
+        for &byte in &trailer[..self.digest.len()] {
+            self.push(byte)?;
+        }
+
+        // A full block does not imply a trailing zero, so no empty block needs to follow it.
+        if self.len > 0 || !self.maximal {
+            self.flush_block(false)?;
+        }
+
+        self.sink.write_all(&[0])?;
+        Ok(self.written + 1)
+    }
+
+    /// Terminates a partially written frame, so that receivers discard it at the delimiter.
+    fn abort(mut self) -> Result<(), IoError> {
+        if self.written > 0 {
+            self.sink.write_all(&[0])?;
+        }
+        Ok(())
+    }
+}
+
+impl<S> Writer for CobsWriter<S>
+where
+    S: Sink,
+{
+    fn write(&mut self, bytes: &[u8]) -> Result<(), EncodeError> {
+        for &byte in bytes {
+            let byte = byte | core::mem::take(&mut self.flags);
+            self.digest.update(&[byte]);
+            if let Err(err) = self.push(byte) {
+                self.error = Some(err);
+                return Err(EncodeError::Other("Failed to write to the sink"));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AccelerometerI16;
+    use crate::versions::{Version1, Version1DataFrame};
+    use crate::{deserialize, SensorId, Vector3Data};
+    use bincode::enc::Encoder;
+    use bincode::Decode;
+
+    /// A frame whose encoding fails after more than a block was written.
+    #[derive(Decode)]
+    struct Failing;
+
+    impl Encode for Failing {
+        fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+            Encode::encode(&[1_u8; 300], encoder)?;
+            Err(EncodeError::Other("Failed to encode"))
+        }
+    }
+
+    impl DataFrame for Failing {
+        type ProtocolVersion = Version1;
+
+        fn is_meta(&self) -> bool {
+            false
+        }
+
+        fn target(&self) -> SensorId {
+            SensorId::default()
+        }
+    }
+
+    /// Records the data written to it.
+    struct Recorder {
+        buffer: [u8; 1024],
+        len: usize,
+    }
+
+    impl Sink for &mut Recorder {
+        fn write_all(&mut self, data: &[u8]) -> Result<(), IoError> {
+            self.buffer[self.len..self.len + data.len()].copy_from_slice(data);
+            self.len += data.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation, clippy::expect_used)]
+    fn test_byte_stuffing() {
+        let mut data = [0_u8; 600];
+        for (index, byte) in data.iter_mut().enumerate() {
+            *byte = (index % 300 + 1) as u8;
+        }
+        data[3] = 0;
+        data[510] = 0;
+
+        let mut expected = [0_u8; 1024];
+        for length in [0, 1, 4, 253, 254, 255, 256, 508, 509, 511, 600] {
+            let mut recorder = Recorder {
+                buffer: [0; 1024],
+                len: 0,
+            };
+
+            let mut writer = CobsWriter::new(&mut recorder, Checksum::None);
+            Writer::write(&mut writer, &data[..length]).expect("Failed to write");
+            let written = writer.finish().expect("Failed to finish");
+
+            let expected_length = corncobs::encode_buf(&data[..length], &mut expected);
+            assert_eq!(written, expected_length);
+            assert_eq!(&recorder.buffer[..written], &expected[..expected_length]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-io")]
+    #[allow(clippy::unwrap_used)]
+    fn test_serialize_to_writer() {
+        use crate::serialize_with_checksum;
+
+        let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+        let frame = Version1DataFrame::new(0, 0, 0, u32::MAX, 12, 0, value);
+
+        for checksum in [Checksum::None, Checksum::Crc16, Checksum::Crc32] {
+            let mut expected = [0_u8; 80];
+            let range = serialize_with_checksum(frame.clone(), checksum, &mut expected).unwrap();
+
+            let mut buffer = [0_u8; 64];
+            let mut writer = &mut buffer[..];
+            let written = serialize_to_writer(frame.clone(), checksum, &mut writer).unwrap();
+            assert_eq!(&buffer[..written], &expected[range]);
+
+            let (_read, data) = deserialize(&mut buffer[..written]).unwrap();
+            assert_eq!(data.version, Version1);
+            assert_eq!(data.data, frame);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-io")]
+    fn test_sink_error() {
+        use embedded_io::ErrorKind;
+
+        let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+        let frame = Version1DataFrame::new(0, 0, 0, u32::MAX, 12, 0, value);
+
+        let mut buffer = [0_u8; 16];
+        let mut writer = &mut buffer[..];
+        assert!(matches!(
+            serialize_to_writer(frame, Checksum::None, &mut writer),
+            Err(SerializationError::Io(IoError::EmbeddedIo(
+                ErrorKind::WriteZero
+            )))
+        ));
+    }
+
+    #[test]
+    fn test_encode_error() {
+        let mut recorder = Recorder {
+            buffer: [0xAA; 1024],
+            len: 0,
+        };
+        assert!(matches!(
+            encode_into_sink(&Failing.into_versioned(), Checksum::None, &mut recorder),
+            Err(SerializationError::BincodeError(_))
+        ));
+
+        // The first block was already written and is terminated by the delimiter.
+        assert_eq!(recorder.len, 1 + MAX_BLOCK_LEN + 1);
+        assert_eq!(recorder.buffer[recorder.len - 1], 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[allow(clippy::unwrap_used)]
+    fn test_serialize_to_std_writer() {
+        let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+        let frame = Version1DataFrame::new(0, 0, 0, u32::MAX, 12, 0, value);
+
+        let mut buffer = Vec::new();
+        let written = serialize_to_std_writer(frame.clone(), Checksum::Crc16, &mut buffer).unwrap();
+        assert_eq!(written, buffer.len());
+        assert_eq!(buffer.last(), Some(&0));
+
+        let (_read, data) = deserialize(&mut buffer).unwrap();
+        assert_eq!(data.data, frame);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    ArrayData, BatteryState, GnssFix, GnssPosition, Identifier, LinearRanges, RawValue, ScalarData,
+    Vector2Data, Vector3Data, Vector4Data,
+};
+
+/// Provides access to the numeric components of a sensor reading.
+///
+/// The [`SensorDataType`](crate::SensorDataType) derive forwards it to the wrapped type, and the
+/// [`SerialSensors`](crate::SerialSensors) derive dispatches it to the type of each variant.
+pub trait Components {
+    /// Returns the component at the specified index, converted to an [`f32`].
+    ///
+    /// Returns `None` if the index is out of range or the data is not numeric.
+    fn component(&self, index: usize) -> Option<f32>;
+}
+
+impl<T> Components for ScalarData<T>
+where
+    T: RawValue,
+{
+    fn component(&self, index: usize) -> Option<f32> {
+        match index {
+            0 => Some(self.value.to_f32()),
+            _ => None,
+        }
+    }
+}
+
+impl<T> Components for Vector2Data<T>
+where
+    T: RawValue,
+{
+    fn component(&self, index: usize) -> Option<f32> {
+        match index {
+            0 => Some(self.x.to_f32()),
+            1 => Some(self.y.to_f32()),
+            _ => None,
+        }
+    }
+}
+
+impl<T> Components for Vector3Data<T>
+where
+    T: RawValue,
+{
+    fn component(&self, index: usize) -> Option<f32> {
+        match index {
+            0 => Some(self.x.to_f32()),
+            1 => Some(self.y.to_f32()),
+            2 => Some(self.z.to_f32()),
+            _ => None,
+        }
+    }
+}
+
+impl<T> Components for Vector4Data<T>
+where
+    T: RawValue,
+{
+    fn component(&self, index: usize) -> Option<f32> {
+        match index {
+            0 => Some(self.a.to_f32()),
+            1 => Some(self.b.to_f32()),
+            2 => Some(self.c.to_f32()),
+            3 => Some(self.d.to_f32()),
+            _ => None,
+        }
+    }
+}
+
+impl<T, const N: usize> Components for ArrayData<T, N>
+where
+    T: RawValue,
+{
+    fn component(&self, index: usize) -> Option<f32> {
+        self.values.get(index).map(|value| value.to_f32())
+    }
+}
+
+impl Components for GnssPosition {
+    fn component(&self, index: usize) -> Option<f32> {
+        match index {
+            0 => Some(self.latitude.to_f32()),
+            1 => Some(self.longitude.to_f32()),
+            2 => Some(self.altitude.to_f32()),
+            _ => None,
+        }
+    }
+}
+
+/// Implements [`Components`] for types that carry no numeric components.
+macro_rules! impl_non_numeric {
+    ($($type:ty),+) => {
+        $(
+            impl Components for $type {
+                fn component(&self, _index: usize) -> Option<f32> {
+                    None
+                }
+            }
+        )+
+    };
+}
+
+impl_non_numeric!(BatteryState, GnssFix, LinearRanges);
+
+impl<const N: usize> Components for Identifier<N> {
+    fn component(&self, _index: usize) -> Option<f32> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Q8_8;
+
+    #[test]
+    fn test_components() {
+        let value = Vector3Data::new(1_i16, -2, 3);
+        assert_eq!(value.component(1), Some(-2.0));
+        assert_eq!(value.component(3), None);
+
+        let value = ScalarData::new(Q8_8::from_bits(0x0180));
+        assert_eq!(value.component(0), Some(1.5));
+
+        let value = ArrayData::new([7_u16; 64]);
+        assert_eq!(value.component(63), Some(7.0));
+        assert_eq!(value.component(64), None);
+
+        assert_eq!(BatteryState::default().component(0), None);
+    }
+}
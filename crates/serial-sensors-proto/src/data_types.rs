@@ -1,12 +1,22 @@
+mod array;
+mod battery_state;
+mod fixed_point;
+mod gnss;
 mod identifier;
 mod linear_ranges;
+mod opaque_payload;
 mod scalar;
 mod vector2;
 mod vector3;
 mod vector4;
 
+pub use array::ArrayData;
+pub use battery_state::BatteryState;
+pub use fixed_point::{Q16_16, Q32_32, Q8_8};
+pub use gnss::{GnssFix, GnssFixType, GnssPosition};
 pub use identifier::{Identifier, IdentifierCode};
-pub use linear_ranges::LinearRanges;
+pub use linear_ranges::{ConversionError, LinearRanges, RawValue, ScaleOp};
+pub use opaque_payload::OpaquePayload;
 pub use scalar::ScalarData;
 pub use vector2::Vector2Data;
 pub use vector3::Vector3Data;
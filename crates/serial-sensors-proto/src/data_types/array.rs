@@ -0,0 +1,134 @@
+use crate::MaxEncodedLen;
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+use core::ops::{Deref, DerefMut};
+
+/// A fixed-size array of uniformly typed values, e.g. the zones of a multi-zone sensor.
+///
+/// On the wire, the values are encoded back-to-back without a length prefix.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(C)]
+pub struct ArrayData<T, const N: usize> {
+    /// The values.
+    pub values: [T; N],
+}
+
+impl<T, const N: usize> ArrayData<T, N> {
+    /// The number of values.
+    pub const LEN: usize = N;
+
+    /// Initializes a new [`ArrayData`] instance.
+    pub const fn new(values: [T; N]) -> Self {
+        Self { values }
+    }
+}
+
+impl<T, const N: usize> Default for ArrayData<T, N>
+where
+    T: Default + Copy,
+{
+    fn default() -> Self {
+        Self::new([T::default(); N])
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayData<T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.values
+    }
+}
+
+impl<T, const N: usize> DerefMut for ArrayData<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.values
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ArrayData<T, N> {
+    fn from(value: [T; N]) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, const N: usize> From<ArrayData<T, N>> for [T; N] {
+    fn from(value: ArrayData<T, N>) -> Self {
+        value.values
+    }
+}
+
+impl<T, const N: usize> Encode for ArrayData<T, N>
+where
+    T: Encode,
+{
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.values
+            .iter()
+            .try_for_each(|value| Encode::encode(value, encoder))
+    }
+}
+
+impl<T, const N: usize> Decode for ArrayData<T, N>
+where
+    T: Decode + Default + Copy,
+{
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let mut data = Self::default();
+        for value in &mut data.values {
+            *value = Decode::decode(decoder)?;
+        }
+        Ok(data)
+    }
+}
+
+impl<'de, T, const N: usize> BorrowDecode<'de> for ArrayData<T, N>
+where
+    T: Decode + Default + Copy,
+{
+    fn borrow_decode<D: BorrowDecoder<'de>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        ArrayData::decode(decoder)
+    }
+}
+
+impl<T, const N: usize> MaxEncodedLen for ArrayData<T, N>
+where
+    T: MaxEncodedLen,
+{
+    const MAX_ENCODED_LEN: usize = N * T::MAX_ENCODED_LEN;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serializer::SERIALIZATION_CONFIG;
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_array_data_u16_serialization() {
+        let input_data = ArrayData::<u16, 4>::new([1, 2, 3, 0xFFFF]);
+
+        // The serialization target buffer.
+        let mut buffer = [0_u8; 1024];
+
+        // Serialize the data
+        let num_serialized =
+            bincode::encode_into_slice(input_data, &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to serialize");
+
+        // Ensure the serialized content is correct; there is no length prefix.
+        assert_eq!(num_serialized, 4 * 2);
+        assert_eq!(&buffer[..num_serialized], &[1, 0, 2, 0, 3, 0, 0xFF, 0xFF]);
+
+        // Deserialize the data
+        let (deserialized, _): (ArrayData<u16, 4>, _) =
+            bincode::decode_from_slice(&buffer[..num_serialized], SERIALIZATION_CONFIG)
+                .expect("Failed to deserialize");
+        assert_eq!(deserialized, input_data);
+        assert_eq!(deserialized[3], 0xFFFF);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::MaxEncodedLen;
+use bincode::{Decode, Encode};
+
+/// The state of a battery, as reported by a fuel gauge.
+#[derive(Encode, Decode, Default, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(C)]
+pub struct BatteryState {
+    /// The state of charge, in percent (0..=100).
+    ///
+    /// If unsupported, set to [`u8::MAX`].
+    pub state_of_charge: u8,
+    /// Indicates whether the battery is currently being charged.
+    pub charging: bool,
+    /// The cell voltage, in millivolts.
+    ///
+    /// If unsupported, set to [`u16::MAX`].
+    pub cell_voltage: u16,
+}
+
+impl BatteryState {
+    /// Initializes a new [`BatteryState`] instance.
+    #[must_use]
+    pub const fn new(state_of_charge: u8, charging: bool, cell_voltage: u16) -> Self {
+        Self {
+            state_of_charge,
+            charging,
+            cell_voltage,
+        }
+    }
+
+    /// Returns the state of charge in percent, or `None` if unsupported.
+    #[must_use]
+    pub const fn state_of_charge_percent(&self) -> Option<u8> {
+        if self.state_of_charge == u8::MAX {
+            None
+        } else {
+            Some(self.state_of_charge)
+        }
+    }
+
+    /// Returns the cell voltage in millivolts, or `None` if unsupported.
+    #[must_use]
+    pub const fn cell_voltage_millivolts(&self) -> Option<u16> {
+        if self.cell_voltage == u16::MAX {
+            None
+        } else {
+            Some(self.cell_voltage)
+        }
+    }
+}
+
+impl MaxEncodedLen for BatteryState {
+    const MAX_ENCODED_LEN: usize =
+        u8::MAX_ENCODED_LEN + bool::MAX_ENCODED_LEN + u16::MAX_ENCODED_LEN;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serializer::SERIALIZATION_CONFIG;
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_serialization() {
+        let input_data = BatteryState::new(87, true, 3_912);
+
+        // The serialization target buffer.
+        let mut buffer = [0_u8; 4];
+
+        // Serialize the data
+        let num_serialized =
+            bincode::encode_into_slice(input_data, &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to serialize");
+        assert_eq!(num_serialized, 4);
+        assert_eq!(buffer, [87, 1, 0x48, 0x0F]);
+
+        // Deserialize the data
+        let (deserialized, _): (BatteryState, _) =
+            bincode::decode_from_slice(&buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to deserialize");
+        assert_eq!(deserialized, input_data);
+        assert_eq!(deserialized.state_of_charge_percent(), Some(87));
+        assert_eq!(deserialized.cell_voltage_millivolts(), Some(3_912));
+    }
+
+    #[test]
+    fn test_unsupported_fields() {
+        let state = BatteryState::new(u8::MAX, false, u16::MAX);
+        assert_eq!(state.state_of_charge_percent(), None);
+        assert_eq!(state.cell_voltage_millivolts(), None);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{MaxEncodedLen, RawValue};
+use bincode::{Decode, Encode};
+
+/// Implements a signed fixed-point number type over an integer type.
+macro_rules! impl_fixed_point {
+    ($comment:literal, $type:ident, $bits:ty, $frac_bits:literal) => {
+        #[doc = $comment]
+        ///
+        /// On the wire, the value is encoded as its underlying integer representation.
+        #[derive(
+            Encode, Decode, Default, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash,
+        )]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[repr(transparent)]
+        pub struct $type($bits);
+
+        impl $type {
+            /// The number of fractional bits.
+            pub const FRAC_BITS: u32 = $frac_bits;
+
+            /// The smallest representable value.
+            pub const MIN: Self = Self(<$bits>::MIN);
+
+            /// The largest representable value.
+            pub const MAX: Self = Self(<$bits>::MAX);
+
+            /// Creates a value from its underlying integer representation.
+            #[must_use]
+            pub const fn from_bits(bits: $bits) -> Self {
+                Self(bits)
+            }
+
+            /// Returns the underlying integer representation.
+            #[must_use]
+            pub const fn to_bits(self) -> $bits {
+                self.0
+            }
+
+            /// Converts the value to an [`f32`], rounding to the nearest representable value
+            /// if the precision is insufficient.
+            #[must_use]
+            #[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+            pub fn to_f32(self) -> f32 {
+                self.0 as f32 / (1_u64 << $frac_bits) as f32
+            }
+
+            /// Converts the value to an [`f64`], rounding to the nearest representable value
+            /// if the precision is insufficient.
+            #[must_use]
+            #[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / (1_u64 << $frac_bits) as f64
+            }
+
+            /// Converts an [`f32`], rounding to the nearest representable value.
+            ///
+            /// Values outside of the representable range saturate; `NaN` is converted to zero.
+            #[must_use]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+            pub fn from_f32(value: f32) -> Self {
+                let scaled = value * (1_u64 << $frac_bits) as f32;
+                let rounded = if scaled < 0.0 {
+                    scaled - 0.5
+                } else {
+                    scaled + 0.5
+                };
+                Self(rounded as $bits)
+            }
+
+            /// Converts an [`f64`], rounding to the nearest representable value.
+            ///
+            /// Values outside of the representable range saturate; `NaN` is converted to zero.
+            #[must_use]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+            pub fn from_f64(value: f64) -> Self {
+                let scaled = value * (1_u64 << $frac_bits) as f64;
+                let rounded = if scaled < 0.0 {
+                    scaled - 0.5
+                } else {
+                    scaled + 0.5
+                };
+                Self(rounded as $bits)
+            }
+        }
+
+        impl MaxEncodedLen for $type {
+            const MAX_ENCODED_LEN: usize = <$bits>::MAX_ENCODED_LEN;
+        }
+
+        impl RawValue for $type {
+            fn to_f32(self) -> f32 {
+                $type::to_f32(self)
+            }
+        }
+    };
+}
+
+impl_fixed_point!(
+    "A signed 16-bit fixed-point number with 8 fractional bits (Q8.8).",
+    Q8_8,
+    i16,
+    8
+);
+impl_fixed_point!(
+    "A signed 32-bit fixed-point number with 16 fractional bits (Q16.16).",
+    Q16_16,
+    i32,
+    16
+);
+impl_fixed_point!(
+    "A signed 64-bit fixed-point number with 32 fractional bits (Q32.32).",
+    Q32_32,
+    i64,
+    32
+);
+
+impl From<Q8_8> for f32 {
+    fn from(value: Q8_8) -> Self {
+        f32::from(value.0) / 256.0
+    }
+}
+
+impl From<Q8_8> for f64 {
+    fn from(value: Q8_8) -> Self {
+        f64::from(value.0) / 256.0
+    }
+}
+
+impl From<Q16_16> for f64 {
+    fn from(value: Q16_16) -> Self {
+        f64::from(value.0) / 65_536.0
+    }
+}
+
+#[cfg(feature = "fixed")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fixed")))]
+mod fixed_interop {
+    use super::{Q16_16, Q32_32, Q8_8};
+    use fixed::types::{I16F16, I32F32, I8F8};
+
+    impl From<I8F8> for Q8_8 {
+        fn from(value: I8F8) -> Self {
+            Self::from_bits(value.to_bits())
+        }
+    }
+
+    impl From<Q8_8> for I8F8 {
+        fn from(value: Q8_8) -> Self {
+            Self::from_bits(value.to_bits())
+        }
+    }
+
+    impl From<I16F16> for Q16_16 {
+        fn from(value: I16F16) -> Self {
+            Self::from_bits(value.to_bits())
+        }
+    }
+
+    impl From<Q16_16> for I16F16 {
+        fn from(value: Q16_16) -> Self {
+            Self::from_bits(value.to_bits())
+        }
+    }
+
+    impl From<I32F32> for Q32_32 {
+        fn from(value: I32F32) -> Self {
+            Self::from_bits(value.to_bits())
+        }
+    }
+
+    impl From<Q32_32> for I32F32 {
+        fn from(value: Q32_32) -> Self {
+            Self::from_bits(value.to_bits())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serializer::SERIALIZATION_CONFIG;
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_serialization() {
+        let input_data = Q8_8::from_f32(-1.5);
+        assert_eq!(input_data.to_bits(), -384);
+
+        // The serialization target buffer.
+        let mut buffer = [0_u8; 8];
+
+        // Serialize the data; it is encoded as the underlying integer.
+        let num_serialized =
+            bincode::encode_into_slice(input_data, &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to serialize");
+        assert_eq!(num_serialized, 2);
+        assert_eq!(&buffer[..num_serialized], &(-384_i16).to_le_bytes());
+
+        // Deserialize the data
+        let (deserialized, _): (Q8_8, _) =
+            bincode::decode_from_slice(&buffer[..num_serialized], SERIALIZATION_CONFIG)
+                .expect("Failed to deserialize");
+        assert_eq!(deserialized, input_data);
+
+        let num_serialized =
+            bincode::encode_into_slice(Q32_32::from_bits(1), &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to serialize");
+        assert_eq!(num_serialized, 8);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_float_conversions() {
+        assert_eq!(f32::from(Q8_8::from_bits(0x0180)), 1.5);
+        assert_eq!(f64::from(Q8_8::from_bits(-1)), -1.0 / 256.0);
+        assert_eq!(f64::from(Q16_16::from_bits(i32::MIN)), -32_768.0);
+        assert_eq!(f64::from(Q16_16::from_bits(1)), 1.0 / 65_536.0);
+
+        assert_eq!(Q16_16::from_f64(-0.25).to_bits(), -16_384);
+        assert_eq!(Q16_16::from_f32(0.000_01).to_bits(), 1);
+        assert_eq!(Q32_32::from_f64(2.5).to_f64(), 2.5);
+        assert_eq!(Q8_8::from_f32(1000.0), Q8_8::MAX);
+        assert_eq!(Q8_8::from_f32(-1000.0), Q8_8::MIN);
+        assert_eq!(Q8_8::from_f32(f32::NAN), Q8_8::default());
+    }
+
+    #[test]
+    #[cfg(feature = "fixed")]
+    fn test_fixed_interop() {
+        use fixed::types::{I16F16, I32F32, I8F8};
+
+        let value = I16F16::from_num(-3.75);
+        assert_eq!(I16F16::from(Q16_16::from(value)), value);
+        assert_eq!(Q8_8::from(I8F8::from_num(1.5)), Q8_8::from_f32(1.5));
+        assert_eq!(I32F32::from(Q32_32::from_f64(2.5)), I32F32::from_num(2.5));
+    }
+}
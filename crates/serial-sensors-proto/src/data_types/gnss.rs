@@ -0,0 +1,219 @@
+use crate::MaxEncodedLen;
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+
+/// The number of fixed-point units per degree of latitude or longitude.
+const UNITS_PER_DEGREE: f64 = 10_000_000.0;
+
+/// The number of millimeters per meter.
+const MILLIS_PER_METER: f64 = 1_000.0;
+
+/// A geodetic GNSS position in fixed-point representation.
+///
+/// Latitude and longitude are expressed in units of 10<sup>-7</sup> degrees, which resolves
+/// to roughly one centimeter at the equator; the altitude is expressed in millimeters
+/// above mean sea level.
+#[derive(Encode, Decode, Default, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(C)]
+pub struct GnssPosition {
+    /// The latitude, in 10<sup>-7</sup> degrees. Positive values are north of the equator.
+    pub latitude: i32,
+    /// The longitude, in 10<sup>-7</sup> degrees. Positive values are east of Greenwich.
+    pub longitude: i32,
+    /// The altitude above mean sea level, in millimeters.
+    pub altitude: i32,
+}
+
+impl GnssPosition {
+    /// Initializes a new [`GnssPosition`] instance.
+    #[must_use]
+    pub const fn new(latitude: i32, longitude: i32, altitude: i32) -> Self {
+        Self {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+
+    /// Returns the latitude in degrees.
+    #[must_use]
+    pub fn latitude_degrees(&self) -> f64 {
+        f64::from(self.latitude) / UNITS_PER_DEGREE
+    }
+
+    /// Returns the longitude in degrees.
+    #[must_use]
+    pub fn longitude_degrees(&self) -> f64 {
+        f64::from(self.longitude) / UNITS_PER_DEGREE
+    }
+
+    /// Returns the altitude above mean sea level in meters.
+    #[must_use]
+    pub fn altitude_meters(&self) -> f64 {
+        f64::from(self.altitude) / MILLIS_PER_METER
+    }
+}
+
+/// The type of a GNSS fix.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum GnssFixType {
+    /// No position fix.
+    #[default]
+    NoFix = 0x00,
+    /// Dead reckoning only.
+    DeadReckoning = 0x01,
+    /// A two-dimensional fix, i.e. without altitude.
+    Fix2D = 0x02,
+    /// A three-dimensional fix.
+    Fix3D = 0x03,
+    /// A GNSS fix combined with dead reckoning.
+    GnssDeadReckoning = 0x04,
+    /// Time only; the position is not valid.
+    TimeOnly = 0x05,
+}
+
+impl Encode for GnssFixType {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        Encode::encode(&(*self as u8), encoder)
+    }
+}
+
+impl Decode for GnssFixType {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let value: u8 = Decode::decode(decoder)?;
+        match value {
+            0x00 => Ok(GnssFixType::NoFix),
+            0x01 => Ok(GnssFixType::DeadReckoning),
+            0x02 => Ok(GnssFixType::Fix2D),
+            0x03 => Ok(GnssFixType::Fix3D),
+            0x04 => Ok(GnssFixType::GnssDeadReckoning),
+            0x05 => Ok(GnssFixType::TimeOnly),
+            _ => Err(DecodeError::Other("Unknown GNSS fix type")),
+        }
+    }
+}
+
+impl<'a> BorrowDecode<'a> for GnssFixType {
+    fn borrow_decode<D: BorrowDecoder<'a>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        GnssFixType::decode(decoder)
+    }
+}
+
+/// The quality of a GNSS fix.
+#[derive(Encode, Decode, Default, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(C)]
+pub struct GnssFix {
+    /// The type of the fix.
+    pub fix_type: GnssFixType,
+    /// The number of satellites used in the solution.
+    pub satellites_used: u8,
+    /// The horizontal dilution of precision, in units of 0.01.
+    ///
+    /// If unsupported, set to [`u16::MAX`].
+    pub hdop: u16,
+}
+
+impl GnssFix {
+    /// Initializes a new [`GnssFix`] instance.
+    #[must_use]
+    pub const fn new(fix_type: GnssFixType, satellites_used: u8, hdop: u16) -> Self {
+        Self {
+            fix_type,
+            satellites_used,
+            hdop,
+        }
+    }
+
+    /// Returns the horizontal dilution of precision, or `None` if unsupported.
+    #[must_use]
+    pub fn hdop_value(&self) -> Option<f32> {
+        if self.hdop == u16::MAX {
+            None
+        } else {
+            Some(f32::from(self.hdop) / 100.0)
+        }
+    }
+
+    /// Indicates whether the fix provides a valid position.
+    #[must_use]
+    pub const fn has_position(&self) -> bool {
+        !matches!(self.fix_type, GnssFixType::NoFix | GnssFixType::TimeOnly)
+    }
+}
+
+impl MaxEncodedLen for GnssPosition {
+    const MAX_ENCODED_LEN: usize = 3 * i32::MAX_ENCODED_LEN;
+}
+
+impl MaxEncodedLen for GnssFixType {
+    const MAX_ENCODED_LEN: usize = u8::MAX_ENCODED_LEN;
+}
+
+impl MaxEncodedLen for GnssFix {
+    const MAX_ENCODED_LEN: usize =
+        GnssFixType::MAX_ENCODED_LEN + u8::MAX_ENCODED_LEN + u16::MAX_ENCODED_LEN;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serializer::SERIALIZATION_CONFIG;
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_position_serialization() {
+        let input_data = GnssPosition::new(481_366_900, 115_755_000, 519_000);
+        assert!((input_data.latitude_degrees() - 48.13669).abs() < 1e-9);
+        assert!((input_data.longitude_degrees() - 11.5755).abs() < 1e-9);
+        assert!((input_data.altitude_meters() - 519.0).abs() < 1e-9);
+
+        // The serialization target buffer.
+        let mut buffer = [0_u8; 12];
+
+        // Serialize the data
+        let num_serialized =
+            bincode::encode_into_slice(input_data, &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to serialize");
+        assert_eq!(num_serialized, 3 * 4);
+
+        // Deserialize the data
+        let (deserialized, _): (GnssPosition, _) =
+            bincode::decode_from_slice(&buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to deserialize");
+        assert_eq!(deserialized, input_data);
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_fix_serialization() {
+        let input_data = GnssFix::new(GnssFixType::Fix3D, 11, 87);
+        assert!(input_data.has_position());
+        assert_eq!(input_data.hdop_value(), Some(0.87));
+
+        let mut buffer = [0_u8; 4];
+        let num_serialized =
+            bincode::encode_into_slice(input_data, &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to serialize");
+        assert_eq!(num_serialized, 4);
+        assert_eq!(buffer, [0x03, 11, 87, 0]);
+
+        let (deserialized, _): (GnssFix, _) =
+            bincode::decode_from_slice(&buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to deserialize");
+        assert_eq!(deserialized, input_data);
+
+        buffer[0] = 0x42;
+        let result: Result<(GnssFix, usize), _> =
+            bincode::decode_from_slice(&buffer, SERIALIZATION_CONFIG);
+        assert!(result.is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{SensorId, ValueType};
+use crate::{MaxEncodedLen, SensorId, ValueType};
 use bincode::de::{BorrowDecoder, Decoder};
 use bincode::enc::Encoder;
 use bincode::error::{DecodeError, EncodeError};
@@ -115,6 +115,14 @@ impl<const N: usize> DerefMut for Identifier<N> {
     }
 }
 
+impl MaxEncodedLen for IdentifierCode {
+    const MAX_ENCODED_LEN: usize = u8::MAX_ENCODED_LEN;
+}
+
+impl<const N: usize> MaxEncodedLen for Identifier<N> {
+    const MAX_ENCODED_LEN: usize = SensorId::MAX_ENCODED_LEN + IdentifierCode::MAX_ENCODED_LEN + N;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
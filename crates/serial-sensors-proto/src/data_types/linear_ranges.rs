@@ -1,5 +1,8 @@
-use crate::SensorId;
-use bincode::{Decode, Encode};
+use crate::{MaxEncodedLen, ScalarData, SensorId, Vector3Data, Vector4Data};
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
 
 /// Value interpretation information for linear value readings with uniform behavior
 /// across all axes.
@@ -14,8 +17,8 @@ pub struct LinearRanges {
     ///
     /// A value could be represented using 16 bits, but only have 12 bit range.
     pub resolution_bits: u16,
-    /// The type of scale operation. Currently, it always implies a division.
-    pub scale_op: u8,
+    /// The type of scale operation.
+    pub scale_op: ScaleOp,
     /// The amount by which to scale the value.
     pub scale: i32,
     /// The number of decimal points in `meas_range_max` and `meas_range_min`, used
@@ -27,30 +30,387 @@ pub struct LinearRanges {
     pub offset_decimals: u8,
 }
 
+/// The operation used to scale a raw value with the [`LinearRanges::scale`].
+///
+/// Sensor datasheets specify sensitivities either in LSB per unit, which requires a
+/// division, or in units per LSB, which requires a multiplication.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ScaleOp {
+    /// The value is divided by the scale, i.e. the scale is given in LSB per unit.
+    #[default]
+    Divide,
+    /// The value is multiplied by the scale, i.e. the scale is given in units per LSB.
+    Multiply,
+    /// The value is shifted right by `scale` bits, i.e. divided by 2^`scale`;
+    /// negative scales shift left. The scale decimals are ignored.
+    Shift,
+    /// The value is sign-extended from `resolution_bits` two's-complement bits, then divided
+    /// by the scale, e.g. for 12-bit readings that are transmitted in 16-bit fields.
+    SignExtend,
+    /// An operation unknown to this version of the crate. Values cannot be converted.
+    Unknown(u8),
+}
+
+impl From<u8> for ScaleOp {
+    fn from(value: u8) -> Self {
+        match value {
+            0x00 => ScaleOp::Divide,
+            0x01 => ScaleOp::Multiply,
+            0x02 => ScaleOp::Shift,
+            0x03 => ScaleOp::SignExtend,
+            other => ScaleOp::Unknown(other),
+        }
+    }
+}
+
+impl From<ScaleOp> for u8 {
+    fn from(value: ScaleOp) -> Self {
+        match value {
+            ScaleOp::Divide => 0x00,
+            ScaleOp::Multiply => 0x01,
+            ScaleOp::Shift => 0x02,
+            ScaleOp::SignExtend => 0x03,
+            ScaleOp::Unknown(other) => other,
+        }
+    }
+}
+
+impl Encode for ScaleOp {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        Encode::encode(&u8::from(*self), encoder)
+    }
+}
+
+impl Decode for ScaleOp {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let value: u8 = Decode::decode(decoder)?;
+        Ok(ScaleOp::from(value))
+    }
+}
+
+impl<'a> BorrowDecode<'a> for ScaleOp {
+    fn borrow_decode<D: BorrowDecoder<'a>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        ScaleOp::decode(decoder)
+    }
+}
+
+impl MaxEncodedLen for ScaleOp {
+    const MAX_ENCODED_LEN: usize = u8::MAX_ENCODED_LEN;
+}
+
+/// A value conversion error.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ConversionError {
+    /// The scale operation is unknown.
+    UnknownScaleOp(u8),
+    /// The scale is zero, or the result is not representable.
+    OutOfRange,
+}
+
+impl core::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConversionError::UnknownScaleOp(op) => write!(f, "unknown scale operation {op}"),
+            ConversionError::OutOfRange => f.write_str("value out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+/// Powers of ten that are representable as an [`f32`].
+const POW10_F32: [f32; 39] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19, 1e20, 1e21, 1e22, 1e23, 1e24, 1e25, 1e26, 1e27, 1e28, 1e29, 1e30, 1e31, 1e32,
+    1e33, 1e34, 1e35, 1e36, 1e37, 1e38,
+];
+
+/// Returns 10^`exp`, or infinity if it is not representable.
+fn pow10_f32(exp: u8) -> f32 {
+    POW10_F32
+        .get(usize::from(exp))
+        .copied()
+        .unwrap_or(f32::INFINITY)
+}
+
+/// Returns 2^`exp`, or zero or infinity if it is not representable.
+///
+/// Exponents below -126 are represented as subnormal numbers.
+#[allow(clippy::cast_sign_loss)]
+fn pow2_f32(exp: i32) -> f32 {
+    match exp {
+        ..=-150 => 0.0,
+        -149..=-127 => f32::from_bits(1 << (exp + 149)),
+        128.. => f32::INFINITY,
+        _ => f32::from_bits(((exp + 127) as u32) << 23),
+    }
+}
+
+/// Returns 10^`exp`.
+fn pow10_i128(exp: u32) -> Result<i128, ConversionError> {
+    10_i128.checked_pow(exp).ok_or(ConversionError::OutOfRange)
+}
+
+/// Returns 2^`exp`.
+fn pow2_i128(exp: u32) -> Result<i128, ConversionError> {
+    2_i128.checked_pow(exp).ok_or(ConversionError::OutOfRange)
+}
+
+/// Multiplies, failing on overflow.
+fn mul(lhs: i128, rhs: i128) -> Result<i128, ConversionError> {
+    lhs.checked_mul(rhs).ok_or(ConversionError::OutOfRange)
+}
+
+/// Divides and rounds half away from zero, failing on division by zero.
+fn div_round(numerator: i128, denominator: i128) -> Result<i128, ConversionError> {
+    let quotient = numerator
+        .checked_div(denominator)
+        .ok_or(ConversionError::OutOfRange)?;
+    let remainder = numerator % denominator;
+    if remainder.unsigned_abs() * 2 >= denominator.unsigned_abs() {
+        let sign = numerator.signum() * denominator.signum();
+        Ok(quotient + sign)
+    } else {
+        Ok(quotient)
+    }
+}
+
+/// A raw sensor reading component that can be converted using [`LinearRanges`].
+pub trait RawValue: Copy {
+    /// Converts the value to an [`f32`], rounding to the nearest representable value
+    /// if the precision is insufficient.
+    fn to_f32(self) -> f32;
+}
+
+/// Implements [`RawValue`] for primitive types.
+macro_rules! impl_raw_value {
+    ($($type:ty),+) => {
+        $(
+            impl RawValue for $type {
+                #[allow(
+                    clippy::cast_lossless,
+                    clippy::cast_possible_truncation,
+                    clippy::cast_precision_loss
+                )]
+                fn to_f32(self) -> f32 {
+                    self as f32
+                }
+            }
+        )+
+    };
+}
+
+impl_raw_value!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+
 impl LinearRanges {
     /// Converts a value using this instance's information.
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-    #[allow(clippy::cast_precision_loss)]
-    #[must_use]
-    pub fn convert(&self, value: f32) -> f32 {
-        let scale = self.scale as f32 / 10.0_f32.powi(i32::from(self.scale_decimals));
-        if self.offset != 0 {
-            let offset = self.offset as f32 / 10.0_f32.powi(i32::from(self.offset_decimals));
-            value / scale + offset
+    ///
+    /// ## Errors
+    /// Returns an error if the scale operation is unknown, the scale is zero, or the result
+    /// is not finite.
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    pub fn convert(&self, value: f32) -> Result<f32, ConversionError> {
+        if self.scale == 0 && matches!(self.scale_op, ScaleOp::Divide | ScaleOp::SignExtend) {
+            return Err(ConversionError::OutOfRange);
+        }
+
+        let scale = self.scale as f32 / pow10_f32(self.scale_decimals);
+        let value = match self.scale_op {
+            ScaleOp::Divide => value / scale,
+            ScaleOp::Multiply => value * scale,
+            ScaleOp::Shift => value / pow2_f32(self.scale),
+            ScaleOp::SignExtend => self.sign_extend(value as i64) as f32 / scale,
+            ScaleOp::Unknown(op) => return Err(ConversionError::UnknownScaleOp(op)),
+        };
+
+        let value = if self.offset != 0 {
+            value + self.offset as f32 / pow10_f32(self.offset_decimals)
         } else {
-            value / scale
+            value
+        };
+
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(ConversionError::OutOfRange)
+        }
+    }
+
+    /// Converts a scalar reading using this instance's information.
+    ///
+    /// ## Errors
+    /// Returns an error if any component could not be converted.
+    pub fn convert_scalar<T: RawValue>(
+        &self,
+        value: ScalarData<T>,
+    ) -> Result<ScalarData<f32>, ConversionError> {
+        Ok(ScalarData::new(self.convert(value.value.to_f32())?))
+    }
+
+    /// Converts a three-dimensional reading using this instance's information.
+    ///
+    /// ## Errors
+    /// Returns an error if any component could not be converted.
+    pub fn convert_vector3<T: RawValue>(
+        &self,
+        value: Vector3Data<T>,
+    ) -> Result<Vector3Data<f32>, ConversionError> {
+        Ok(Vector3Data::new(
+            self.convert(value.x.to_f32())?,
+            self.convert(value.y.to_f32())?,
+            self.convert(value.z.to_f32())?,
+        ))
+    }
+
+    /// Converts a four-dimensional reading using this instance's information.
+    ///
+    /// ## Errors
+    /// Returns an error if any component could not be converted.
+    pub fn convert_vector4<T: RawValue>(
+        &self,
+        value: Vector4Data<T>,
+    ) -> Result<Vector4Data<f32>, ConversionError> {
+        Ok(Vector4Data::new(
+            self.convert(value.a.to_f32())?,
+            self.convert(value.b.to_f32())?,
+            self.convert(value.c.to_f32())?,
+            self.convert(value.d.to_f32())?,
+        ))
+    }
+
+    /// Converts a value using integer math only.
+    ///
+    /// Returns the physical value multiplied by 10^`decimals`, rounded half away from zero,
+    /// e.g. milli-units for three decimals.
+    ///
+    /// ## Errors
+    /// Returns an error if the scale operation is unknown, the scale is zero, or the result
+    /// is not representable.
+    pub fn convert_scaled(&self, value: i64, decimals: u8) -> Result<i64, ConversionError> {
+        let decimals = u32::from(decimals);
+        let scale_decimals = u32::from(self.scale_decimals);
+        let scale = i128::from(self.scale);
+
+        let mut result = match self.scale_op {
+            ScaleOp::Divide => div_round(
+                mul(i128::from(value), pow10_i128(decimals + scale_decimals)?)?,
+                scale,
+            )?,
+            ScaleOp::Multiply => div_round(
+                mul(mul(i128::from(value), scale)?, pow10_i128(decimals)?)?,
+                pow10_i128(scale_decimals)?,
+            )?,
+            ScaleOp::Shift => {
+                let value = mul(i128::from(value), pow10_i128(decimals)?)?;
+                let factor = pow2_i128(self.scale.unsigned_abs())?;
+                if self.scale >= 0 {
+                    div_round(value, factor)?
+                } else {
+                    mul(value, factor)?
+                }
+            }
+            ScaleOp::SignExtend => div_round(
+                mul(
+                    i128::from(self.sign_extend(value)),
+                    pow10_i128(decimals + scale_decimals)?,
+                )?,
+                scale,
+            )?,
+            ScaleOp::Unknown(op) => return Err(ConversionError::UnknownScaleOp(op)),
+        };
+
+        if self.offset != 0 {
+            let offset = mul(i128::from(self.offset), pow10_i128(decimals)?)?;
+            let offset = div_round(offset, pow10_i128(u32::from(self.offset_decimals))?)?;
+            result = result
+                .checked_add(offset)
+                .ok_or(ConversionError::OutOfRange)?;
+        }
+
+        i64::try_from(result).map_err(|_| ConversionError::OutOfRange)
+    }
+
+    /// Converts a scalar reading using integer math only. See [`LinearRanges::convert_scaled`].
+    ///
+    /// ## Errors
+    /// Returns an error if any component could not be converted.
+    pub fn convert_scalar_scaled<T: Into<i64>>(
+        &self,
+        value: ScalarData<T>,
+        decimals: u8,
+    ) -> Result<ScalarData<i64>, ConversionError> {
+        Ok(ScalarData::new(
+            self.convert_scaled(value.value.into(), decimals)?,
+        ))
+    }
+
+    /// Converts a three-dimensional reading using integer math only.
+    /// See [`LinearRanges::convert_scaled`].
+    ///
+    /// ## Errors
+    /// Returns an error if any component could not be converted.
+    pub fn convert_vector3_scaled<T: Into<i64>>(
+        &self,
+        value: Vector3Data<T>,
+        decimals: u8,
+    ) -> Result<Vector3Data<i64>, ConversionError> {
+        Ok(Vector3Data::new(
+            self.convert_scaled(value.x.into(), decimals)?,
+            self.convert_scaled(value.y.into(), decimals)?,
+            self.convert_scaled(value.z.into(), decimals)?,
+        ))
+    }
+
+    /// Converts a four-dimensional reading using integer math only.
+    /// See [`LinearRanges::convert_scaled`].
+    ///
+    /// ## Errors
+    /// Returns an error if any component could not be converted.
+    pub fn convert_vector4_scaled<T: Into<i64>>(
+        &self,
+        value: Vector4Data<T>,
+        decimals: u8,
+    ) -> Result<Vector4Data<i64>, ConversionError> {
+        Ok(Vector4Data::new(
+            self.convert_scaled(value.a.into(), decimals)?,
+            self.convert_scaled(value.b.into(), decimals)?,
+            self.convert_scaled(value.c.into(), decimals)?,
+            self.convert_scaled(value.d.into(), decimals)?,
+        ))
+    }
+
+    /// Sign-extends a value from `resolution_bits` two's-complement bits.
+    fn sign_extend(&self, value: i64) -> i64 {
+        let bits = u32::from(self.resolution_bits);
+        if bits == 0 || bits >= i64::BITS {
+            return value;
         }
+
+        let shift = i64::BITS - bits;
+        (value << shift) >> shift
     }
 }
 
+impl MaxEncodedLen for LinearRanges {
+    const MAX_ENCODED_LEN: usize = SensorId::MAX_ENCODED_LEN
+        + u16::MAX_ENCODED_LEN
+        + ScaleOp::MAX_ENCODED_LEN
+        + i32::MAX_ENCODED_LEN
+        + u8::MAX_ENCODED_LEN
+        + i32::MAX_ENCODED_LEN
+        + u8::MAX_ENCODED_LEN;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::serializer::SERIALIZATION_CONFIG;
 
     #[test]
-    #[cfg(feature = "std")]
+    #[allow(clippy::expect_used)]
     fn test_calibrate_temp() {
         let mag_data = LinearRanges {
             target: SensorId::default(),
@@ -59,12 +419,12 @@ mod tests {
             ..Default::default()
         };
 
-        let result = mag_data.convert(16640.0);
-        assert_eq!(result, 1.015625);
+        let result = mag_data.convert(16640.0).expect("Failed to convert");
+        assert!((result - 1.015_625).abs() < f32::EPSILON);
     }
 
     #[test]
-    #[cfg(feature = "std")]
+    #[allow(clippy::expect_used)]
     fn test_calibrate_mag() {
         let mag_data = LinearRanges {
             target: SensorId::default(),
@@ -73,12 +433,12 @@ mod tests {
             ..Default::default()
         };
 
-        let result = mag_data.convert(384.0);
-        assert_eq!(result, 0.3490909);
+        let result = mag_data.convert(384.0).expect("Failed to convert");
+        assert!((result - 0.349_090_9).abs() < f32::EPSILON);
     }
 
     #[test]
-    #[cfg(feature = "std")]
+    #[allow(clippy::expect_used)]
     fn test_calibrate_accel() {
         let mag_data = LinearRanges {
             target: SensorId::default(),
@@ -88,8 +448,8 @@ mod tests {
             ..Default::default()
         };
 
-        let result = mag_data.convert(73.0);
-        assert_eq!(result, 29.125);
+        let result = mag_data.convert(73.0).expect("Failed to convert");
+        assert!((result - 29.125).abs() < f32::EPSILON);
     }
 
     #[test]
@@ -122,4 +482,199 @@ mod tests {
         assert_eq!(deserialized.resolution_bits, 12);
         assert_eq!(count, 17);
     }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_calibrate_pressure() {
+        // Readings in Pa, as documented for `PressureU32`, converted to hPa.
+        let pressure = LinearRanges {
+            target: crate::SensorIds::PRESSUREU32,
+            resolution_bits: 32,
+            scale: 100,
+            ..Default::default()
+        };
+
+        let result = pressure.convert(101_325.0).expect("Failed to convert");
+        assert!((result - 1013.25).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_convert_vectors() {
+        let ranges = LinearRanges {
+            target: SensorId::default(),
+            resolution_bits: 16,
+            scale: 16384,
+            ..Default::default()
+        };
+
+        let value = ranges.convert_scalar(ScalarData::new(-8192_i16));
+        assert_eq!(value, Ok(ScalarData::new(-0.5)));
+
+        let value = ranges.convert_vector3(Vector3Data::new(16384_i16, -16384, 4096));
+        assert_eq!(value, Ok(Vector3Data::new(1.0, -1.0, 0.25)));
+
+        let value = ranges.convert_vector4(Vector4Data::new(0_u8, 64, 128, 255));
+        assert_eq!(
+            value,
+            Ok(Vector4Data::new(
+                0.0,
+                0.003_906_25,
+                0.007_812_5,
+                255.0 / 16384.0
+            ))
+        );
+    }
+
+    #[test]
+    fn test_convert_scaled() {
+        // 1100 LSB/gauss with an offset of -0.25 gauss, in milligauss.
+        let ranges = LinearRanges {
+            target: SensorId::default(),
+            resolution_bits: 12,
+            scale: 1100,
+            offset: -25,
+            offset_decimals: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(ranges.convert_scaled(384, 3), Ok(99));
+        assert_eq!(ranges.convert_scaled(-384, 3), Ok(-599));
+        assert_eq!(ranges.convert_scaled(384, 6), Ok(99_091));
+
+        let value = ranges.convert_vector3_scaled(Vector3Data::new(1100_i16, 0, -1100), 3);
+        assert_eq!(value, Ok(Vector3Data::new(750, -250, -1250)));
+
+        let value = ranges.convert_scalar_scaled(ScalarData::new(2200_u16), 0);
+        assert_eq!(value, Ok(ScalarData::new(2)));
+
+        let value = ranges.convert_vector4_scaled(Vector4Data::new(0_i32, 1, 2, 3), 3);
+        assert_eq!(value, Ok(Vector4Data::new(-250, -249, -248, -247)));
+
+        // 16384 LSB/g with two decimals, i.e. 163.84 LSB/g.
+        let ranges = LinearRanges {
+            scale: 16384,
+            scale_decimals: 2,
+            ..ranges
+        };
+        assert_eq!(ranges.convert_scaled(16384, 0), Ok(100));
+
+        let invalid = LinearRanges::default();
+        assert_eq!(invalid.convert(1.0), Err(ConversionError::OutOfRange));
+        assert_eq!(
+            invalid.convert_scaled(1, 0),
+            Err(ConversionError::OutOfRange)
+        );
+        assert_eq!(
+            ranges.convert_scaled(i64::MAX, 30),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_scale_ops() {
+        // 0.061 mg/LSB, i.e. a multiplication.
+        let ranges = LinearRanges {
+            target: SensorId::default(),
+            resolution_bits: 16,
+            scale_op: ScaleOp::Multiply,
+            scale: 61,
+            scale_decimals: 3,
+            ..Default::default()
+        };
+        let result = ranges.convert(1000.0).expect("Failed to convert");
+        assert!((result - 61.0).abs() < 1e-3);
+        assert_eq!(ranges.convert_scaled(1000, 0), Ok(61));
+        assert_eq!(ranges.convert_scaled(-1000, 3), Ok(-61_000));
+
+        // Q4 readings, i.e. a shift by four bits.
+        let ranges = LinearRanges {
+            scale_op: ScaleOp::Shift,
+            scale: 4,
+            scale_decimals: 3,
+            ..ranges
+        };
+        let result = ranges.convert(-40.0).expect("Failed to convert");
+        assert!((result + 2.5).abs() < f32::EPSILON);
+        assert_eq!(ranges.convert_scaled(-40, 1), Ok(-25));
+
+        let ranges = LinearRanges {
+            scale: -2,
+            ..ranges
+        };
+        let result = ranges.convert(3.0).expect("Failed to convert");
+        assert!((result - 12.0).abs() < f32::EPSILON);
+        assert_eq!(ranges.convert_scaled(3, 0), Ok(12));
+
+        // Shifts by subnormal powers of two remain finite for small values.
+        let ranges = LinearRanges {
+            scale: -127,
+            ..ranges
+        };
+        assert_eq!(ranges.convert(1.0), Ok(1.701_411_8e38));
+        let ranges = LinearRanges {
+            scale: -150,
+            ..ranges
+        };
+        assert_eq!(ranges.convert(1.0), Err(ConversionError::OutOfRange));
+
+        // 12-bit two's complement readings in 16-bit fields, at 1 LSB/mg.
+        let ranges = LinearRanges {
+            resolution_bits: 12,
+            scale_op: ScaleOp::SignExtend,
+            scale: 1000,
+            scale_decimals: 0,
+            ..ranges
+        };
+        let result = ranges.convert(4095.0).expect("Failed to convert");
+        assert!((result + 0.001).abs() < f32::EPSILON);
+        assert_eq!(ranges.convert_scaled(0x0800, 3), Ok(-2048));
+        assert_eq!(ranges.convert_scaled(0x07FF, 3), Ok(2047));
+
+        let ranges = LinearRanges { scale: 0, ..ranges };
+        assert_eq!(ranges.convert(1.0), Err(ConversionError::OutOfRange));
+
+        let ranges = LinearRanges {
+            scale_op: ScaleOp::Multiply,
+            scale: i32::MAX,
+            ..ranges
+        };
+        assert_eq!(ranges.convert(f32::MAX), Err(ConversionError::OutOfRange));
+
+        let ranges = LinearRanges {
+            scale_op: ScaleOp::Unknown(0x42),
+            ..ranges
+        };
+        assert_eq!(
+            ranges.convert(1.0),
+            Err(ConversionError::UnknownScaleOp(0x42))
+        );
+        assert_eq!(
+            ranges.convert_scaled(1, 0),
+            Err(ConversionError::UnknownScaleOp(0x42))
+        );
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_scale_op_serialization() {
+        let mut buffer = [0_u8; 8];
+        for code in 0..=u8::MAX {
+            let op = ScaleOp::from(code);
+            assert_eq!(u8::from(op), code);
+
+            let num_serialized = bincode::encode_into_slice(op, &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to serialize");
+            assert_eq!(num_serialized, ScaleOp::MAX_ENCODED_LEN);
+            assert_eq!(buffer[0], code);
+
+            let (decoded, _): (ScaleOp, usize) =
+                bincode::decode_from_slice(&buffer, SERIALIZATION_CONFIG)
+                    .expect("Failed to deserialize");
+            assert_eq!(decoded, op);
+        }
+
+        assert_eq!(ScaleOp::from(0x01), ScaleOp::Multiply);
+        assert_eq!(ScaleOp::from(0x04), ScaleOp::Unknown(0x04));
+    }
 }
@@ -0,0 +1,188 @@
+use crate::MaxEncodedLen;
+use bincode::de::read::Reader;
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::write::Writer;
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+use core::ops::Deref;
+
+/// An opaque, fixed-capacity byte payload.
+///
+/// On the wire, the payload is encoded as a length byte followed by its raw bytes. It therefore
+/// holds at most 255 bytes, regardless of `N`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OpaquePayload<const N: usize> {
+    /// The number of used bytes.
+    len: usize,
+    /// The payload bytes; unused bytes are always zero.
+    bytes: [u8; N],
+}
+
+impl<const N: usize> Default for OpaquePayload<N> {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            bytes: [0; N],
+        }
+    }
+}
+
+impl<const N: usize> OpaquePayload<N> {
+    /// The capacity of the payload, in bytes; this is `N`, limited to the 255 bytes the length
+    /// byte can describe.
+    pub const CAPACITY: usize = if N < u8::MAX as usize {
+        N
+    } else {
+        u8::MAX as usize
+    };
+
+    /// Initializes a new [`OpaquePayload`] instance from the specified bytes.
+    ///
+    /// Returns `None` if the data exceeds the capacity.
+    #[must_use]
+    pub fn new(data: &[u8]) -> Option<Self> {
+        if data.len() > Self::CAPACITY {
+            return None;
+        }
+
+        let mut bytes = [0; N];
+        bytes[..data.len()].copy_from_slice(data);
+        Some(Self {
+            len: data.len(),
+            bytes,
+        })
+    }
+
+    /// Returns the payload bytes.
+    #[must_use]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Returns the unused part of the payload buffer.
+    ///
+    /// Callers must reset bytes they do not [`advance`](Self::advance) over back to zero.
+    pub(crate) fn spare_capacity_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes[self.len..Self::CAPACITY]
+    }
+
+    /// Marks the specified number of bytes of the spare capacity as used.
+    pub(crate) fn advance(&mut self, count: usize) {
+        self.len = (self.len + count).min(Self::CAPACITY);
+    }
+}
+
+impl<const N: usize> Deref for OpaquePayload<N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for OpaquePayload<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for OpaquePayload<N> {
+    type Error = ();
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::new(value).ok_or(())
+    }
+}
+
+impl<const N: usize> Encode for OpaquePayload<N> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        // The length never exceeds the capacity, which fits the length byte.
+        Encode::encode(&(self.len as u8), encoder)?;
+        encoder.writer().write(self.as_slice())
+    }
+}
+
+impl<const N: usize> Decode for OpaquePayload<N> {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let len: u8 = Decode::decode(decoder)?;
+        let len = usize::from(len);
+        if len > Self::CAPACITY {
+            return Err(DecodeError::Other("Opaque payload exceeds capacity"));
+        }
+
+        let mut payload = Self::default();
+        decoder.claim_bytes_read(len)?;
+        decoder.reader().read(&mut payload.bytes[..len])?;
+        payload.len = len;
+        Ok(payload)
+    }
+}
+
+impl<'de, const N: usize> BorrowDecode<'de> for OpaquePayload<N> {
+    fn borrow_decode<D: BorrowDecoder<'de>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        OpaquePayload::decode(decoder)
+    }
+}
+
+impl<const N: usize> MaxEncodedLen for OpaquePayload<N> {
+    const MAX_ENCODED_LEN: usize = u8::MAX_ENCODED_LEN + Self::CAPACITY;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serializer::SERIALIZATION_CONFIG;
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_payload_serialization() {
+        let input_data = OpaquePayload::<8>::new(&[1, 2, 3]).expect("Payload too large");
+
+        // The serialization target buffer.
+        let mut buffer = [0_u8; 8];
+
+        // Serialize the data
+        let num_serialized =
+            bincode::encode_into_slice(input_data, &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to serialize");
+
+        // Ensure the serialized content is correct
+        assert_eq!(num_serialized, 4);
+        assert_eq!(&buffer[..num_serialized], &[3, 1, 2, 3]);
+
+        // Deserialize the data
+        let result = bincode::decode_from_slice(&buffer[..num_serialized], SERIALIZATION_CONFIG)
+            .expect("Failed to deserialize");
+        let deserialized: OpaquePayload<8> = result.0;
+        assert_eq!(deserialized, input_data);
+        assert_eq!(result.1, 4);
+
+        // The length byte delimits the payload from subsequent data.
+        let (deserialized, read): (OpaquePayload<8>, usize) =
+            bincode::decode_from_slice(&[2, 1, 2, 3], SERIALIZATION_CONFIG)
+                .expect("Failed to deserialize");
+        assert_eq!(deserialized.as_slice(), &[1, 2]);
+        assert_eq!(read, 3);
+
+        let result: Result<(OpaquePayload<8>, usize), _> =
+            bincode::decode_from_slice(&[4, 1, 2, 3], SERIALIZATION_CONFIG);
+        assert!(matches!(result, Err(DecodeError::UnexpectedEnd { .. })));
+    }
+
+    #[test]
+    fn test_capacity() {
+        assert!(OpaquePayload::<2>::new(&[1, 2, 3]).is_none());
+
+        let result: Result<(OpaquePayload<2>, usize), _> =
+            bincode::decode_from_slice(&[3, 1, 2, 3], SERIALIZATION_CONFIG);
+        assert!(result.is_err());
+
+        assert_eq!(OpaquePayload::<300>::CAPACITY, 255);
+        assert!(OpaquePayload::<300>::new(&[1; 256]).is_none());
+        assert_eq!(OpaquePayload::<300>::MAX_ENCODED_LEN, 256);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::MaxEncodedLen;
 use bincode::{Decode, Encode};
 use uniform_array_derive::UniformArray;
 
@@ -43,6 +44,13 @@ impl<T> From<ScalarData<T>> for [T; 1] {
     }
 }
 
+impl<T> MaxEncodedLen for ScalarData<T>
+where
+    T: MaxEncodedLen,
+{
+    const MAX_ENCODED_LEN: usize = T::MAX_ENCODED_LEN;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
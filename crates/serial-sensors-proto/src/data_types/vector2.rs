@@ -1,3 +1,4 @@
+use crate::MaxEncodedLen;
 use bincode::{Decode, Encode};
 use uniform_array_derive::UniformArray;
 
@@ -79,6 +80,13 @@ where
     }
 }
 
+impl<T> MaxEncodedLen for Vector2Data<T>
+where
+    T: MaxEncodedLen,
+{
+    const MAX_ENCODED_LEN: usize = 2 * T::MAX_ENCODED_LEN;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
+use crate::MaxEncodedLen;
 use bincode::{Decode, Encode};
 use uniform_array_derive::UniformArray;
 
@@ -80,6 +81,13 @@ impl From<micromath::Quaternion> for Vector4Data<f32> {
     }
 }
 
+impl<T> MaxEncodedLen for Vector4Data<T>
+where
+    T: MaxEncodedLen,
+{
+    const MAX_ENCODED_LEN: usize = 4 * T::MAX_ENCODED_LEN;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,372 @@
+use crate::versions::{AnyVersionFrame, Version1, Version1DataFrame};
+use crate::{deserialize, deserialize_any, DeserializationError, VersionedDataFrame};
+
+/// The COBS frame delimiter.
+pub(crate) const DELIMITER: u8 = 0x00;
+
+/// A stateful stream decoder for byte-stuffed frames.
+///
+/// Bytes can be fed in arbitrarily sized chunks as they arrive, e.g. from a UART. The decoder
+/// buffers up to `N` bytes until it encounters the zero delimiter and then decodes the
+/// collected frame. Frames that are corrupt, or that exceed the buffer capacity, are dropped
+/// and reported as an error; decoding then resumes at the next delimiter.
+///
+/// ```
+/// # use serial_sensors_proto::{serialize, FrameDecoder, Vector3Data};
+/// # use serial_sensors_proto::types::AccelerometerI16;
+/// # use serial_sensors_proto::versions::Version1DataFrame;
+/// let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+/// let frame = Version1DataFrame::new(0, 0, 0, 1, 12, 0, value);
+///
+/// let mut buffer = [0_u8; 64];
+/// let range = serialize(frame, &mut buffer).unwrap();
+///
+/// let mut decoder = FrameDecoder::<64>::new();
+/// let (first, second) = buffer[range].split_at(10);
+/// assert!(decoder.decode(first).next().is_none());
+///
+/// let frame = decoder.decode(second).next().unwrap().unwrap();
+/// assert_eq!(frame.data.sensor_sequence, 12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameDecoder<const N: usize> {
+    /// The receive buffer.
+    buffer: [u8; N],
+    /// The number of bytes currently held in the buffer.
+    len: usize,
+    /// Set when the current frame overran the buffer and is being skipped.
+    overflowed: bool,
+}
+
+impl<const N: usize> Default for FrameDecoder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> FrameDecoder<N> {
+    /// Creates a new, empty decoder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            buffer: [0; N],
+            len: 0,
+            overflowed: false,
+        }
+    }
+
+    /// Returns the number of bytes of the current, incomplete frame.
+    #[must_use]
+    pub const fn buffered(&self) -> usize {
+        self.len
+    }
+
+    /// Discards any partially received frame.
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.overflowed = false;
+    }
+
+    /// Feeds a single byte into the decoder.
+    ///
+    /// Returns `None` while a frame is incomplete, or when an empty frame (i.e. a repeated
+    /// delimiter) was received.
+    ///
+    /// ## Errors
+    /// Returns an error when a frame was completed but had to be dropped, either because it
+    /// did not fit the buffer or because it could not be decoded.
+    pub fn push(
+        &mut self,
+        byte: u8,
+    ) -> Option<Result<VersionedDataFrame<Version1, Version1DataFrame>, DeserializationError>> {
+        self.push_with(byte, deserialize)
+    }
+
+    /// Feeds a single byte into the decoder, accepting frames of any supported protocol version.
+    ///
+    /// Returns `None` while a frame is incomplete, or when an empty frame (i.e. a repeated
+    /// delimiter) was received.
+    ///
+    /// ## Errors
+    /// Returns an error when a frame was completed but had to be dropped, either because it
+    /// did not fit the buffer or because it could not be decoded.
+    pub fn push_any(&mut self, byte: u8) -> Option<Result<AnyVersionFrame, DeserializationError>> {
+        self.push_with(byte, deserialize_any)
+    }
+
+    /// Feeds a single byte into the decoder and deserializes completed frames using
+    /// the specified function.
+    fn push_with<T>(
+        &mut self,
+        byte: u8,
+        deserialize: Deserializer<T>,
+    ) -> Option<Result<T, DeserializationError>> {
+        if byte != DELIMITER {
+            if self.len < N {
+                self.buffer[self.len] = byte;
+                self.len += 1;
+            } else {
+                self.overflowed = true;
+            }
+            return None;
+        }
+
+        let len = self.len;
+        let overflowed = self.overflowed;
+        self.reset();
+
+        if overflowed {
+            return Some(Err(DeserializationError::FrameTooLong));
+        }
+
+        if len == 0 {
+            return None;
+        }
+
+        Some(deserialize(&mut self.buffer[..len]).map(|(_, frame)| frame))
+    }
+
+    /// Feeds a chunk of bytes into the decoder and returns an iterator over all frames
+    /// completed by it.
+    ///
+    /// The input is only consumed as far as the iterator is advanced; bytes that were not
+    /// visited when the iterator is dropped are discarded.
+    pub fn decode<'a>(&'a mut self, data: &'a [u8]) -> DecodeFrames<'a, N> {
+        DecodeFrames {
+            decoder: self,
+            data: data.iter(),
+            deserialize,
+        }
+    }
+
+    /// Feeds a chunk of bytes into the decoder and returns an iterator over all frames
+    /// completed by it, accepting frames of any supported protocol version.
+    ///
+    /// The input is only consumed as far as the iterator is advanced; bytes that were not
+    /// visited when the iterator is dropped are discarded.
+    pub fn decode_any<'a>(&'a mut self, data: &'a [u8]) -> DecodeFrames<'a, N, AnyVersionFrame> {
+        DecodeFrames {
+            decoder: self,
+            data: data.iter(),
+            deserialize: deserialize_any,
+        }
+    }
+}
+
+/// A function deserializing a single un-delimited frame.
+pub(crate) type Deserializer<T> = fn(&mut [u8]) -> Result<(usize, T), DeserializationError>;
+
+/// An iterator over the frames decoded from a chunk of data.
+/// See [`FrameDecoder::decode`] and [`FrameDecoder::decode_any`].
+#[derive(Debug)]
+pub struct DecodeFrames<'a, const N: usize, T = VersionedDataFrame<Version1, Version1DataFrame>> {
+    decoder: &'a mut FrameDecoder<N>,
+    data: core::slice::Iter<'a, u8>,
+    deserialize: Deserializer<T>,
+}
+
+impl<const N: usize, T> Iterator for DecodeFrames<'_, N, T> {
+    type Item = Result<T, DeserializationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &byte in self.data.by_ref() {
+            if let Some(result) = self.decoder.push_with(byte, self.deserialize) {
+                return Some(result);
+            }
+        }
+        None
+    }
+}
+
+/// Feeds chunks read from a source into a [`FrameDecoder`].
+///
+/// This holds the state shared by the reader adapters, which only perform the actual reads:
+/// frames are decoded from the current chunk until it is exhausted, after which the adapter
+/// reads the next chunk into [`ChunkDecoder::chunk`] and passes its length to
+/// [`ChunkDecoder::filled`]. A zero length marks the end of the source.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub(crate) struct ChunkDecoder<const N: usize, const C: usize, T> {
+    decoder: FrameDecoder<N>,
+    /// The function deserializing completed frames.
+    deserialize: Deserializer<T>,
+    /// The bytes read from the source.
+    chunk: [u8; C],
+    /// The position of the next byte to feed into the decoder.
+    position: usize,
+    /// The number of valid bytes in the chunk.
+    len: usize,
+    /// Set when the source reached end of file.
+    eof: bool,
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize, const C: usize, T> ChunkDecoder<N, C, T> {
+    /// Creates a new decoder with an empty chunk, deserializing frames using the specified
+    /// function.
+    pub(crate) const fn new(deserialize: Deserializer<T>) -> Self {
+        Self {
+            decoder: FrameDecoder::new(),
+            deserialize,
+            chunk: [0; C],
+            position: 0,
+            len: 0,
+            eof: false,
+        }
+    }
+
+    /// Indicates whether the source reached end of file.
+    pub(crate) const fn is_eof(&self) -> bool {
+        self.eof
+    }
+
+    /// Feeds the remaining bytes of the current chunk into the decoder.
+    ///
+    /// Returns `None` when the chunk is exhausted without completing a frame.
+    pub(crate) fn decode(&mut self) -> Option<Result<T, DeserializationError>> {
+        while self.position < self.len {
+            let byte = self.chunk[self.position];
+            self.position += 1;
+            if let Some(result) = self.decoder.push_with(byte, self.deserialize) {
+                return Some(result);
+            }
+        }
+        None
+    }
+
+    /// Returns the buffer to read the next chunk into.
+    pub(crate) fn chunk(&mut self) -> &mut [u8] {
+        &mut self.chunk
+    }
+
+    /// Accepts the next chunk of `len` bytes; zero marks the end of the source.
+    ///
+    /// Returns [`DeserializationError::Truncated`] if the source ended within a frame.
+    pub(crate) fn filled(&mut self, len: usize) -> Option<DeserializationError> {
+        self.position = 0;
+        self.len = len;
+        if len == 0 {
+            self.eof = true;
+            if self.decoder.buffered() > 0 {
+                self.decoder.reset();
+                return Some(DeserializationError::Truncated);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serialize;
+    use crate::types::AccelerometerI16;
+    use crate::Vector3Data;
+
+    #[allow(clippy::expect_used)]
+    fn encode(sequence: u32, buffer: &mut [u8]) -> core::ops::Range<usize> {
+        let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+        let frame = Version1DataFrame::new(0, 0, 0, sequence, sequence, 0, value);
+        serialize(frame, buffer).expect("Failed to serialize")
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_decode_chunked() {
+        let mut first = [0_u8; 64];
+        let mut second = [0_u8; 64];
+        let first_range = encode(1, &mut first);
+        let second_range = encode(2, &mut second);
+        let first = &first[first_range];
+        let second = &second[second_range];
+
+        let mut stream = [0_u8; 58];
+        stream[..29].copy_from_slice(first);
+        stream[29..].copy_from_slice(second);
+
+        let mut decoder = FrameDecoder::<64>::new();
+        let mut sequences = [0_u32; 2];
+        let mut count = 0;
+        for chunk in stream.chunks(7) {
+            for frame in decoder.decode(chunk) {
+                let frame = frame.expect("Failed to decode");
+                sequences[count] = frame.data.global_sequence;
+                count += 1;
+            }
+        }
+
+        assert_eq!(count, 2);
+        assert_eq!(sequences, [1, 2]);
+        assert_eq!(decoder.buffered(), 0);
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_resync_after_garbage() {
+        let mut buffer = [0_u8; 64];
+        let range = encode(7, &mut buffer);
+
+        let mut decoder = FrameDecoder::<64>::new();
+
+        // Joining mid-stream yields a broken frame that must be dropped.
+        let mut frames = decoder.decode(&[0x13, 0x37, 0x42, 0x00]);
+        assert!(frames.next().expect("Expected an error").is_err());
+        assert!(frames.next().is_none());
+
+        let frame = decoder
+            .decode(&buffer[range])
+            .next()
+            .expect("Expected a frame")
+            .expect("Failed to decode");
+        assert_eq!(frame.data.global_sequence, 7);
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_overlong_frame_is_dropped() {
+        let mut buffer = [0_u8; 64];
+        let range = encode(3, &mut buffer);
+
+        let mut decoder = FrameDecoder::<32>::new();
+        let garbage = [0xAA_u8; 40];
+        assert!(decoder.decode(&garbage).next().is_none());
+
+        let mut frames = decoder.decode(&buffer[range]);
+        assert!(matches!(
+            frames.next(),
+            Some(Err(DeserializationError::FrameTooLong))
+        ));
+        assert!(frames.next().is_none());
+
+        let mut buffer = [0_u8; 64];
+        let range = encode(4, &mut buffer);
+        let frame = decoder
+            .decode(&buffer[range])
+            .next()
+            .expect("Expected a frame")
+            .expect("Failed to decode");
+        assert_eq!(frame.data.global_sequence, 4);
+    }
+
+    #[test]
+    fn test_repeated_delimiters_are_skipped() {
+        let mut decoder = FrameDecoder::<32>::new();
+        assert!(decoder.decode(&[0, 0, 0]).next().is_none());
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_decode_any() {
+        let mut buffer = [0_u8; 64];
+        let range = encode(5, &mut buffer);
+
+        let mut decoder = FrameDecoder::<64>::new();
+        let frame = decoder
+            .decode_any(&buffer[range])
+            .next()
+            .expect("Expected a frame")
+            .expect("Failed to decode");
+        assert_eq!(frame.version(), 1);
+        assert_eq!(frame.global_sequence(), Some(5));
+    }
+}
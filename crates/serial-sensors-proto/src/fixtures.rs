@@ -0,0 +1,127 @@
+//! Fixtures shared by the unit tests.
+
+use crate::types::AccelerometerI16;
+use crate::versions::{TimeBase, Version2DataFrame};
+use crate::Vector3Data;
+
+/// Returns the reading used throughout the tests.
+pub fn reading() -> AccelerometerI16 {
+    AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 })
+}
+
+/// Returns a version 1 frame without a global sequence.
+#[cfg(any(feature = "embedded-io", feature = "std"))]
+pub fn frame(sensor_sequence: u32) -> crate::versions::Version1DataFrame {
+    crate::versions::Version1DataFrame::new(0, 0, 0, u32::MAX, sensor_sequence, 0, reading())
+}
+
+/// Returns a version 2 frame taken every millisecond.
+pub fn frame_v2(sequence: u32) -> Version2DataFrame {
+    Version2DataFrame::new(
+        u64::from(sequence) * 1_000_000,
+        TimeBase::BootRelative,
+        sequence,
+        sequence,
+        0,
+        reading(),
+    )
+}
+
+/// A transport that accepts and returns at most three bytes at a time.
+///
+/// Written bytes are appended to the buffer until it is full. Reads return the bytes not yet
+/// read; reads and writes fail once when `fail_at` bytes were read or written.
+#[cfg(any(feature = "embedded-io", feature = "std"))]
+#[cfg_attr(not(all(feature = "embedded-io", feature = "std")), allow(dead_code))]
+pub struct Trickle {
+    pub buffer: [u8; 64],
+    pub len: usize,
+    pub flushed: bool,
+    pub fail_at: usize,
+    read: usize,
+}
+
+#[cfg(any(feature = "embedded-io", feature = "std"))]
+impl Trickle {
+    pub const fn new() -> Self {
+        Self {
+            buffer: [0; 64],
+            len: 0,
+            flushed: false,
+            fail_at: usize::MAX,
+            read: 0,
+        }
+    }
+
+    /// Creates a transport returning the specified data.
+    #[cfg(feature = "std")]
+    pub fn with_data(data: &[u8], fail_at: usize) -> Self {
+        let mut transport = Self::new();
+        transport.buffer[..data.len()].copy_from_slice(data);
+        transport.len = data.len();
+        transport.fail_at = fail_at;
+        transport
+    }
+
+    #[cfg(feature = "embedded-io")]
+    fn accept(&mut self, data: &[u8]) -> Result<usize, embedded_io::ErrorKind> {
+        if self.len == self.fail_at {
+            self.fail_at = usize::MAX;
+            return Err(embedded_io::ErrorKind::ConnectionReset);
+        }
+
+        let count = data.len().min(3).min(self.buffer.len() - self.len);
+        if count == 0 && !data.is_empty() {
+            return Err(embedded_io::ErrorKind::OutOfMemory);
+        }
+
+        self.buffer[self.len..self.len + count].copy_from_slice(&data[..count]);
+        self.len += count;
+        self.flushed = false;
+        Ok(count)
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::ErrorType for Trickle {
+    type Error = embedded_io::ErrorKind;
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Write for Trickle {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.accept(buf)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.flushed = true;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-io-async")]
+impl embedded_io_async::Write for Trickle {
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.accept(buf)
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        self.flushed = true;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Read for Trickle {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.read == self.fail_at {
+            self.fail_at = usize::MAX;
+            return Err(std::io::ErrorKind::ConnectionReset.into());
+        }
+
+        let count = buf.len().min(3).min(self.len - self.read);
+        buf[..count].copy_from_slice(&self.buffer[self.read..self.read + count]);
+        self.read += count;
+        Ok(count)
+    }
+}
@@ -13,6 +13,11 @@
 //! * `micromath` - Enables conversion to and from `micromath` vector types.
 //! * `quaternion` - Forwarded to `micromath` to enable quaternion support.
 //! * `defmt` - Enables deferred formatting support via `defmt`.
+//! * `fixed` - Enables conversion to and from the fixed-point types of the `fixed` crate.
+//! * `embedded-io` - Enables the `FrameSender` and `serialize_to_writer` for `embedded-io` transports.
+//! * `embedded-io-async` - Enables the `AsyncFrameSender` for `embedded-io-async` transports.
+//!   Implies `embedded-io`.
+//! * `tokio` - Enables the `FrameStream` for decoding frames from `tokio` readers. Implies `std`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -29,22 +34,66 @@
     unused_qualifications
 )]
 
+// Allows the derive macros to refer to this crate as `::serial_sensors_proto`.
+extern crate self as serial_sensors_proto;
+
 use bincode::de::{BorrowDecoder, Decoder};
 use bincode::enc::Encoder;
 use bincode::error::{DecodeError, EncodeError};
 use bincode::{BorrowDecode, Decode, Encode};
 use core::fmt::Formatter;
-use serial_sensors_proto_derive::SerialSensors;
+use core::ops::RangeInclusive;
 
+mod checksum;
+#[cfg(any(feature = "embedded-io", feature = "std"))]
+mod cobs_writer;
+mod components;
 mod data_types;
+mod decoder;
+#[cfg(test)]
+mod fixtures;
+mod max_encoded_len;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod registry;
+#[cfg(feature = "embedded-io")]
+#[cfg_attr(docsrs, doc(cfg(feature = "embedded-io")))]
+mod sender;
 mod sensor_id;
 mod serializer;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod std_io;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+mod stream;
 pub mod types;
 pub mod versions;
 
+pub use checksum::*;
+#[cfg(any(feature = "embedded-io", feature = "std"))]
+pub use cobs_writer::*;
+pub use components::*;
 pub use data_types::*;
+pub use decoder::*;
+pub use max_encoded_len::*;
+#[cfg(feature = "std")]
+pub use registry::*;
+#[cfg(feature = "embedded-io")]
+pub use sender::*;
 pub use sensor_id::*;
+pub use serial_sensors_proto_derive::{SensorDataType, SerialSensors};
 pub use serializer::*;
+#[cfg(feature = "std")]
+pub use std_io::*;
+#[cfg(feature = "tokio")]
+pub use stream::FrameStream;
+
+/// Re-exports used by the derive macros; not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use bincode;
+}
 
 /// A protocol version.
 pub trait ProtocolVersion: Default + Encode {
@@ -65,7 +114,7 @@ where
     V: ProtocolVersion,
     D: DataFrame,
 {
-    /// A protocol version byte. Always set to one.
+    /// The protocol version byte.
     pub version: V,
 
     /// The data frame.
@@ -80,34 +129,202 @@ pub enum SensorData {
     #[sensor(id = 0x2, data = ValueType::UInt32, components = 1)]
     SystemClockFrequency(types::SystemClockFrequency),
 
+    /// A counter of events, e.g. interrupts, steps or dropped samples.
+    #[sensor(id = 0x3, data = ValueType::UInt64, components = 1)]
+    EventCounterU64(types::EventCounterU64),
+
+    /// A counter of events, e.g. interrupts, steps or dropped samples.
+    #[sensor(id = 0x3, data = ValueType::UInt128, components = 1)]
+    EventCounterU128(types::EventCounterU128),
+
+    /// A timestamp, expressed in nanoseconds.
+    #[sensor(id = 0x4, data = ValueType::UInt64, components = 1)]
+    TimestampU64(types::TimestampU64),
+
+    /// A signed timestamp or time offset, expressed in nanoseconds.
+    #[sensor(id = 0x4, data = ValueType::SInt128, components = 1)]
+    TimestampI128(types::TimestampI128),
+
+    /// A sensor that measures a bus voltage, typically expressed in millivolts (mV).
+    #[sensor(id = 0x10, data = ValueType::UInt16, components = 1)]
+    BusVoltageU16(types::BusVoltageU16),
+
+    /// A sensor that measures a bus voltage, expressed in volts (V).
+    #[sensor(id = 0x10, data = ValueType::Float32, components = 1)]
+    BusVoltageF32(types::BusVoltageF32),
+
+    /// A sensor that measures the current through a shunt resistor, typically expressed in milliamperes (mA).
+    #[sensor(id = 0x11, data = ValueType::SInt16, components = 1)]
+    ShuntCurrentI16(types::ShuntCurrentI16),
+
+    /// A sensor that measures the current through a shunt resistor, expressed in amperes (A).
+    #[sensor(id = 0x11, data = ValueType::Float32, components = 1)]
+    ShuntCurrentF32(types::ShuntCurrentF32),
+
+    /// A sensor that measures power, typically expressed in milliwatts (mW).
+    #[sensor(id = 0x12, data = ValueType::UInt16, components = 1)]
+    PowerU16(types::PowerU16),
+
+    /// A sensor that measures power, expressed in watts (W).
+    #[sensor(id = 0x12, data = ValueType::Float32, components = 1)]
+    PowerF32(types::PowerF32),
+
+    /// An energy accumulator, typically expressed in millijoules (mJ).
+    #[sensor(id = 0x14, data = ValueType::SInt64, components = 1)]
+    EnergyI64(types::EnergyI64),
+
+    /// The state of a battery, see [`BatteryState`].
+    #[sensor(id = 0x13, data = ValueType::BatteryState, components = 1)]
+    BatteryStateInfo(types::BatteryStateInfo),
+
     /// A sensor that measures the gravity vector, typically expressed in "g".
     #[sensor(id = 0x42, data = ValueType::SInt16, components = 3)]
     AccelerometerI16(types::AccelerometerI16),
 
+    /// A sensor that measures the gravity vector, typically expressed in "g".
+    #[sensor(id = 0x42, data = ValueType::SInt32, components = 3)]
+    AccelerometerI32(types::AccelerometerI32),
+
+    /// A sensor that measures the gravity vector, expressed in m/s².
+    #[sensor(id = 0x42, data = ValueType::Float32, components = 3)]
+    AccelerometerF32(types::AccelerometerF32),
+
+    /// A sensor that measures the gravity vector, expressed in m/s².
+    #[sensor(id = 0x42, data = ValueType::Q16_16, components = 3)]
+    AccelerometerQ16_16(types::AccelerometerQ16_16),
+
     /// A sensor that measures magnetic field strength, typically expressed in units auf Milli-Gauss (mG).
     #[sensor(id = 0x43, data = ValueType::SInt16, components = 3)]
     MagnetometerI16(types::MagnetometerI16),
 
+    /// A sensor that measures magnetic field strength, typically expressed in units auf Milli-Gauss (mG).
+    #[sensor(id = 0x43, data = ValueType::SInt32, components = 3)]
+    MagnetometerI32(types::MagnetometerI32),
+
+    /// A sensor that measures magnetic field strength, expressed in Gauss (G).
+    #[sensor(id = 0x43, data = ValueType::Float32, components = 3)]
+    MagnetometerF32(types::MagnetometerF32),
+
     /// A sensor that measures temperature, typically expressed in °C.
     #[sensor(id = 0x44, data = ValueType::SInt16, components = 1)]
     TemperatureI16(types::TemperatureI16),
 
+    /// A sensor that measures temperature, expressed in °C.
+    #[sensor(id = 0x44, data = ValueType::Q8_8, components = 1)]
+    TemperatureQ8_8(types::TemperatureQ8_8),
+
     /// A sensor that measures angular acceleration, typically expressed in degrees/second.
     #[sensor(id = 0x45, data = ValueType::SInt16, components = 3)]
     GyroscopeI16(types::GyroscopeI16),
 
+    /// A sensor that measures angular acceleration, typically expressed in degrees/second.
+    #[sensor(id = 0x45, data = ValueType::SInt32, components = 3)]
+    GyroscopeI32(types::GyroscopeI32),
+
+    /// A sensor that measures angular acceleration, expressed in radians/second.
+    #[sensor(id = 0x45, data = ValueType::Float32, components = 3)]
+    GyroscopeF32(types::GyroscopeF32),
+
     /// A sensor that measures heading, i.e. degrees towards magnetic north (0..360).
     #[sensor(id = 0x46, data = ValueType::UInt8, components = 1)]
     HeadingI16(types::HeadingI16),
 
+    /// A sensor that measures barometric pressure, expressed in Pascal (Pa).
+    #[sensor(id = 0x47, data = ValueType::UInt32, components = 1)]
+    PressureU32(types::PressureU32),
+
+    /// A sensor that measures barometric pressure, expressed in Pascal (Pa).
+    #[sensor(id = 0x47, data = ValueType::SInt32, components = 1)]
+    PressureI32(types::PressureI32),
+
+    /// A sensor that measures barometric pressure, expressed in Pascal (Pa).
+    #[sensor(id = 0x47, data = ValueType::Float32, components = 1)]
+    PressureF32(types::PressureF32),
+
+    /// Altitude derived from barometric pressure, expressed in meters.
+    #[sensor(id = 0x48, data = ValueType::Float32, components = 1)]
+    AltitudeF32(types::AltitudeF32),
+
+    /// Altitude derived from barometric pressure, expressed in meters.
+    #[sensor(id = 0x48, data = ValueType::Q32_32, components = 1)]
+    AltitudeQ32_32(types::AltitudeQ32_32),
+
+    /// A sensor that measures relative humidity, expressed in 1/100 percent (0.01 %RH).
+    #[sensor(id = 0x49, data = ValueType::UInt16, components = 1)]
+    RelativeHumidityU16(types::RelativeHumidityU16),
+
+    /// A sensor that measures relative humidity, expressed in percent (%RH).
+    #[sensor(id = 0x49, data = ValueType::Float32, components = 1)]
+    RelativeHumidityF32(types::RelativeHumidityF32),
+
+    /// A sensor that reports a volatile organic compounds (VOC) index.
+    #[sensor(id = 0x4A, data = ValueType::UInt16, components = 1)]
+    VocIndexU16(types::VocIndexU16),
+
+    /// A sensor that estimates the equivalent CO₂ concentration, expressed in ppm.
+    #[sensor(id = 0x4B, data = ValueType::UInt16, components = 1)]
+    Eco2U16(types::Eco2U16),
+
+    /// A sensor that measures the CO₂ concentration, expressed in ppm.
+    #[sensor(id = 0x4C, data = ValueType::UInt16, components = 1)]
+    Co2U16(types::Co2U16),
+
+    /// A sensor that measures particulate matter as PM1.0, PM2.5 and PM10, expressed in µg/m³.
+    #[sensor(id = 0x4D, data = ValueType::UInt16, components = 3)]
+    ParticulateMatterU16(types::ParticulateMatterU16),
+
+    /// A sensor that measures illuminance, typically expressed in lux (lx).
+    #[sensor(id = 0x60, data = ValueType::UInt32, components = 1)]
+    IlluminanceU32(types::IlluminanceU32),
+
+    /// A sensor that measures illuminance, expressed in lux (lx).
+    #[sensor(id = 0x60, data = ValueType::Float32, components = 1)]
+    IlluminanceF32(types::IlluminanceF32),
+
+    /// A sensor that measures infrared light intensity as a raw count.
+    #[sensor(id = 0x61, data = ValueType::UInt16, components = 1)]
+    InfraredLightU16(types::InfraredLightU16),
+
+    /// A proximity sensor reporting a raw count; larger values indicate closer objects.
+    #[sensor(id = 0x62, data = ValueType::UInt16, components = 1)]
+    ProximityU16(types::ProximityU16),
+
+    /// A single-zone distance sensor, expressed in millimeters.
+    #[sensor(id = 0x63, data = ValueType::UInt16, components = 1)]
+    DistanceU16(types::DistanceU16),
+
+    /// A multi-zone distance sensor with 8×8 zones in row-major order, expressed in millimeters.
+    #[sensor(id = 0x64, data = ValueType::UInt16, components = 64)]
+    DistanceZonesU16(types::DistanceZonesU16),
+
+    /// A GNSS receiver's geodetic position, see [`GnssPosition`].
+    #[sensor(id = 0x50, data = ValueType::SInt32, components = 3)]
+    GnssPositionI32(types::GnssPositionI32),
+
+    /// A GNSS receiver's velocity over ground as north, east and down components, expressed in mm/s.
+    #[sensor(id = 0x51, data = ValueType::SInt32, components = 3)]
+    GnssVelocityI32(types::GnssVelocityI32),
+
+    /// The type and quality of a GNSS receiver's fix, see [`GnssFix`].
+    #[sensor(id = 0x52, data = ValueType::GnssFix, components = 1)]
+    GnssFixInfo(types::GnssFixInfo),
+
     /// Euler angles, in radians.
     #[sensor(id = 0xF0, data = ValueType::Float32, components = 3)]
     EulerAnglesF32(types::EulerAnglesF32),
 
+    /// Euler angles, in radians.
+    #[sensor(id = 0xF0, data = ValueType::Float64, components = 3)]
+    EulerAnglesF64(types::EulerAnglesF64),
+
     /// An orientation quaternion.
     #[sensor(id = 0xF1, data = ValueType::Float32, components = 4)]
     OrientationQuaternionF32(types::OrientationQuaternionF32),
 
+    /// An orientation quaternion.
+    #[sensor(id = 0xF1, data = ValueType::Float64, components = 4)]
+    OrientationQuaternionF64(types::OrientationQuaternionF64),
+
     /// Identification data.
     #[sensor(id = 0xFE, data = ValueType::LinearRange, components = 1)]
     LinearRanges(types::LinearRangeInfo),
@@ -115,6 +332,22 @@ pub enum SensorData {
     /// Identification data.
     #[sensor(id = 0xFF, data = ValueType::Identifier, components = 64)]
     Identification(types::Identification),
+
+    /// A vendor-specific sensor reading.
+    ///
+    /// The type ID must be from [`SensorData::VENDOR_TYPE_IDS`]; other type IDs fail to
+    /// encode, and [`SensorData::vendor`] rejects them. Readings of unknown vendor sensor
+    /// types are decoded into this variant and encode back to the identical wire
+    /// representation, so that they can be forwarded.
+    #[sensor(vendor)]
+    Vendor {
+        /// The sensor type ID.
+        type_id: u8,
+        /// The field value type.
+        value_type: ValueType,
+        /// The opaque sensor reading.
+        payload: OpaquePayload<{ SensorData::VENDOR_PAYLOAD_CAPACITY }>,
+    },
 }
 
 /// Sensor type tags.
@@ -152,6 +385,10 @@ pub enum ValueType {
     Q16_16 = 0x0E,
     /// 64-bit fixed-point format, Q32.32 (I32F32)
     Q32_32 = 0x0F,
+    /// A battery state description.
+    BatteryState = 0xFC,
+    /// A GNSS fix quality description.
+    GnssFix = 0xFD,
     /// A value range description.
     LinearRange = 0xFE,
     /// An identifier.
@@ -178,6 +415,8 @@ impl TryFrom<u8> for ValueType {
             0x0D => Ok(Self::Q8_8),
             0x0E => Ok(Self::Q16_16),
             0x0F => Ok(Self::Q32_32),
+            0xFC => Ok(Self::BatteryState),
+            0xFD => Ok(Self::GnssFix),
             0xFE => Ok(Self::LinearRange),
             0xFF => Ok(Self::Identifier),
             _ => Err(()),
@@ -186,6 +425,35 @@ impl TryFrom<u8> for ValueType {
 }
 
 impl SensorData {
+    /// The range of sensor type IDs reserved for vendor-specific sensors.
+    pub const VENDOR_TYPE_IDS: RangeInclusive<u8> = 0xC0..=0xDF;
+
+    /// The maximum number of payload bytes of a [`SensorData::Vendor`] reading.
+    pub const VENDOR_PAYLOAD_CAPACITY: usize = 64;
+
+    /// Constructs a vendor-specific reading.
+    ///
+    /// Returns `None` if the type ID is outside of [`SensorData::VENDOR_TYPE_IDS`]
+    /// or the payload exceeds [`SensorData::VENDOR_PAYLOAD_CAPACITY`].
+    #[must_use]
+    pub fn vendor(type_id: u8, value_type: ValueType, payload: &[u8]) -> Option<Self> {
+        if !Self::VENDOR_TYPE_IDS.contains(&type_id) {
+            return None;
+        }
+
+        Some(Self::Vendor {
+            type_id,
+            value_type,
+            payload: OpaquePayload::new(payload)?,
+        })
+    }
+
+    /// Indicates whether the data is a vendor-specific or unknown reading.
+    #[must_use]
+    pub fn is_vendor(&self) -> bool {
+        matches!(self, SensorData::Vendor { .. })
+    }
+
     /// Indicates whether the data resembles metadata.
     #[must_use]
     pub fn is_meta(&self) -> bool {
@@ -212,6 +480,10 @@ impl Encode for ValueType {
     }
 }
 
+impl MaxEncodedLen for ValueType {
+    const MAX_ENCODED_LEN: usize = u8::MAX_ENCODED_LEN;
+}
+
 impl Decode for ValueType {
     fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
         let value: u8 = Decode::decode(decoder)?;
@@ -285,6 +557,14 @@ pub trait DataFrame: Sized + Decode {
     fn target(&self) -> SensorId;
 }
 
+impl<V, D> MaxEncodedLen for VersionedDataFrame<V, D>
+where
+    V: ProtocolVersion + MaxEncodedLen,
+    D: DataFrame + MaxEncodedLen,
+{
+    const MAX_ENCODED_LEN: usize = V::MAX_ENCODED_LEN + D::MAX_ENCODED_LEN;
+}
+
 impl<V, D> Decode for VersionedDataFrame<V, D>
 where
     V: ProtocolVersion + ::bincode::Decode,
@@ -337,6 +617,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::expect_used)]
     fn into_versioned() {
         let frame = Version1DataFrame::new(
             u32::MAX,
@@ -387,6 +668,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unwrap_used)]
     fn test() {
         let instance =
             SensorData::AccelerometerI16(AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 }));
@@ -404,6 +686,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::expect_used, clippy::unwrap_used)]
     fn test_serialize() {
         let value: SensorData = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 }).into();
 
@@ -436,4 +719,487 @@ mod tests {
         assert_eq!(into.y, -2);
         assert_eq!(into.z, 3);
     }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_vendor_round_trip() {
+        let value = SensorData::vendor(0xC1, ValueType::UInt8, &[1, 2, 3]).expect("Invalid data");
+        assert!(value.is_vendor());
+        assert_eq!(value.sensor_type_id(), 0xC1);
+        assert_eq!(value.value_type(), ValueType::UInt8);
+
+        let mut buffer = [0_u8; 1024];
+        let num_serialized =
+            bincode::encode_into_slice(value.clone(), &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to serialize");
+        assert_eq!(&buffer[..num_serialized], &[0xC1, 0x01, 3, 1, 2, 3]);
+
+        let (deserialized, count): (SensorData, _) =
+            bincode::decode_from_slice(&buffer[..num_serialized], SERIALIZATION_CONFIG)
+                .expect("Failed to deserialize");
+        assert_eq!(count, num_serialized);
+        assert_eq!(deserialized, value);
+
+        assert!(SensorData::vendor(0x42, ValueType::UInt8, &[]).is_none());
+
+        // A vendor reading built with a regular type ID would be mistaken for that type.
+        let value = SensorData::Vendor {
+            type_id: 0x42,
+            value_type: ValueType::SInt16,
+            payload: OpaquePayload::new(&[0; 6]).expect("Invalid payload"),
+        };
+        assert!(bincode::encode_into_slice(value, &mut buffer, SERIALIZATION_CONFIG).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_unknown_type_is_forwarded() {
+        // An unknown vendor sensor type.
+        let wire = [0xD0, 0x05, 0x04, 0xDE, 0xAD, 0xBE, 0xEF];
+        let (value, _): (SensorData, _) =
+            bincode::decode_from_slice(&wire, SERIALIZATION_CONFIG).expect("Failed to decode");
+
+        let SensorData::Vendor {
+            type_id,
+            value_type,
+            ref payload,
+        } = value
+        else {
+            panic!("Expected a vendor reading");
+        };
+        assert_eq!(type_id, 0xD0);
+        assert_eq!(value_type, ValueType::UInt32);
+        assert_eq!(payload.as_slice(), &[0xDE, 0xAD, 0xBE, 0xEF]);
+
+        let mut buffer = [0_u8; 16];
+        let num_serialized = bincode::encode_into_slice(value, &mut buffer, SERIALIZATION_CONFIG)
+            .expect("Failed to serialize");
+        assert_eq!(&buffer[..num_serialized], &wire);
+
+        // Unknown sensor types outside of the vendor range cannot be decoded.
+        let result: Result<(SensorData, _), _> = bincode::decode_from_slice(
+            &[0x70, 0x05, 0x04, 0xDE, 0xAD, 0xBE, 0xEF],
+            SERIALIZATION_CONFIG,
+        );
+        assert!(matches!(result, Err(DecodeError::Other(_))));
+    }
+
+    /// Encodes and decodes a reading, asserting its `[type ID, value type]` header.
+    ///
+    /// Returns the number of encoded bytes following the header.
+    #[allow(clippy::expect_used)]
+    fn assert_round_trip(value: &SensorData, header: [u8; 2]) -> usize {
+        let mut buffer = [0_u8; SensorData::MAX_ENCODED_LEN];
+        let num_serialized = bincode::encode_into_slice(value, &mut buffer, SERIALIZATION_CONFIG)
+            .expect("Failed to serialize");
+        assert_eq!(buffer[..2], header);
+
+        let (deserialized, num_read): (SensorData, _) =
+            bincode::decode_from_slice(&buffer[..num_serialized], SERIALIZATION_CONFIG)
+                .expect("Failed to deserialize");
+        assert_eq!(&deserialized, value);
+        assert_eq!(num_read, num_serialized);
+        num_serialized - header.len()
+    }
+
+    #[test]
+    fn test_pressure_types() {
+        use types::{AltitudeF32, PressureF32, PressureI32, PressureU32};
+
+        assert_eq!(SensorIds::PRESSUREU32.id(), SensorIds::PRESSUREF32.id());
+        assert_eq!(SensorIds::PRESSUREI32.value_type(), ValueType::SInt32);
+        assert_eq!(SensorData::components(0x47, ValueType::UInt32), Ok(1));
+        assert_eq!(SensorData::components(0x48, ValueType::Float32), Ok(1));
+
+        for (value, header) in [
+            (
+                SensorData::from(PressureU32::new(ScalarData::new(101_325))),
+                [0x47, ValueType::UInt32 as u8],
+            ),
+            (
+                SensorData::from(PressureI32::new(ScalarData::new(-250))),
+                [0x47, ValueType::SInt32 as u8],
+            ),
+            (
+                SensorData::from(PressureF32::new(ScalarData::new(101_325.0))),
+                [0x47, ValueType::Float32 as u8],
+            ),
+            (
+                SensorData::from(AltitudeF32::new(ScalarData::new(412.5))),
+                [0x48, ValueType::Float32 as u8],
+            ),
+        ] {
+            assert_eq!(assert_round_trip(&value, header), 4);
+        }
+    }
+
+    #[test]
+    fn test_environmental_types() {
+        use types::{
+            Co2U16, Eco2U16, ParticulateMatterU16, RelativeHumidityF32, RelativeHumidityU16,
+            VocIndexU16,
+        };
+
+        assert_eq!(SensorData::components(0x49, ValueType::Float32), Ok(1));
+        assert_eq!(SensorData::components(0x4D, ValueType::UInt16), Ok(3));
+        assert_ne!(SensorIds::RELATIVEHUMIDITYU16, SensorIds::TEMPERATUREI16);
+
+        let particulates = ParticulateMatterU16::new(Vector3Data::new(3, 8, 12));
+        assert_eq!(particulates.pm1_0(), 3);
+        assert_eq!(particulates.pm2_5(), 8);
+        assert_eq!(particulates.pm10(), 12);
+
+        for (value, header, size) in [
+            (
+                SensorData::from(RelativeHumidityU16::new(ScalarData::new(4_512))),
+                [0x49, ValueType::UInt16 as u8],
+                2,
+            ),
+            (
+                SensorData::from(RelativeHumidityF32::new(ScalarData::new(45.12))),
+                [0x49, ValueType::Float32 as u8],
+                4,
+            ),
+            (
+                SensorData::from(VocIndexU16::new(ScalarData::new(100))),
+                [0x4A, ValueType::UInt16 as u8],
+                2,
+            ),
+            (
+                SensorData::from(Eco2U16::new(ScalarData::new(400))),
+                [0x4B, ValueType::UInt16 as u8],
+                2,
+            ),
+            (
+                SensorData::from(Co2U16::new(ScalarData::new(812))),
+                [0x4C, ValueType::UInt16 as u8],
+                2,
+            ),
+            (
+                SensorData::from(particulates),
+                [0x4D, ValueType::UInt16 as u8],
+                3 * 2,
+            ),
+        ] {
+            assert_eq!(assert_round_trip(&value, header), size);
+        }
+    }
+
+    #[test]
+    fn test_gnss_types() {
+        use types::{GnssFixInfo, GnssPositionI32, GnssVelocityI32};
+
+        assert_eq!(SensorData::components(0x50, ValueType::SInt32), Ok(3));
+        assert_eq!(SensorData::components(0x52, ValueType::GnssFix), Ok(1));
+        assert_eq!(ValueType::try_from(0xFD), Ok(ValueType::GnssFix));
+
+        for (value, header, size) in [
+            (
+                SensorData::from(GnssPositionI32::new(GnssPosition::new(
+                    481_366_900,
+                    115_755_000,
+                    519_000,
+                ))),
+                [0x50, ValueType::SInt32 as u8],
+                3 * 4,
+            ),
+            (
+                SensorData::from(GnssVelocityI32::new(Vector3Data::new(1_250, -300, 12))),
+                [0x51, ValueType::SInt32 as u8],
+                3 * 4,
+            ),
+            (
+                SensorData::from(GnssFixInfo::new(GnssFix::new(GnssFixType::Fix3D, 11, 87))),
+                [0x52, ValueType::GnssFix as u8],
+                1 + 1 + 2,
+            ),
+        ] {
+            assert_eq!(assert_round_trip(&value, header), size);
+        }
+    }
+
+    #[test]
+    fn test_power_types() {
+        use types::{
+            BatteryStateInfo, BusVoltageF32, BusVoltageU16, PowerF32, PowerU16, ShuntCurrentF32,
+            ShuntCurrentI16,
+        };
+
+        assert_eq!(SensorData::components(0x11, ValueType::SInt16), Ok(1));
+        assert_eq!(SensorData::components(0x13, ValueType::BatteryState), Ok(1));
+        assert_eq!(ValueType::try_from(0xFC), Ok(ValueType::BatteryState));
+
+        for (value, header, size) in [
+            (
+                SensorData::from(BusVoltageU16::new(ScalarData::new(4_980))),
+                [0x10, ValueType::UInt16 as u8],
+                2,
+            ),
+            (
+                SensorData::from(BusVoltageF32::new(ScalarData::new(4.98))),
+                [0x10, ValueType::Float32 as u8],
+                4,
+            ),
+            (
+                SensorData::from(ShuntCurrentI16::new(ScalarData::new(-120))),
+                [0x11, ValueType::SInt16 as u8],
+                2,
+            ),
+            (
+                SensorData::from(ShuntCurrentF32::new(ScalarData::new(-0.12))),
+                [0x11, ValueType::Float32 as u8],
+                4,
+            ),
+            (
+                SensorData::from(PowerU16::new(ScalarData::new(598))),
+                [0x12, ValueType::UInt16 as u8],
+                2,
+            ),
+            (
+                SensorData::from(PowerF32::new(ScalarData::new(0.598))),
+                [0x12, ValueType::Float32 as u8],
+                4,
+            ),
+            (
+                SensorData::from(BatteryStateInfo::new(BatteryState::new(87, true, 3_912))),
+                [0x13, ValueType::BatteryState as u8],
+                1 + 1 + 2,
+            ),
+        ] {
+            assert_eq!(assert_round_trip(&value, header), size);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_light_and_distance_types() {
+        use types::{
+            DistanceU16, DistanceZonesU16, IlluminanceF32, IlluminanceU32, InfraredLightU16,
+            ProximityU16,
+        };
+
+        assert_eq!(SensorData::components(0x63, ValueType::UInt16), Ok(1));
+        assert_eq!(SensorData::components(0x64, ValueType::UInt16), Ok(64));
+        assert_ne!(SensorIds::DISTANCEU16, SensorIds::DISTANCEZONESU16);
+
+        let mut zones = [0_u16; 64];
+        for (zone, distance) in zones.iter_mut().enumerate() {
+            *distance = u16::try_from(zone).expect("Invalid zone") * 10;
+        }
+
+        for (value, header, size) in [
+            (
+                SensorData::from(IlluminanceU32::new(ScalarData::new(120_000))),
+                [0x60, ValueType::UInt32 as u8],
+                4,
+            ),
+            (
+                SensorData::from(IlluminanceF32::new(ScalarData::new(320.5))),
+                [0x60, ValueType::Float32 as u8],
+                4,
+            ),
+            (
+                SensorData::from(InfraredLightU16::new(ScalarData::new(812))),
+                [0x61, ValueType::UInt16 as u8],
+                2,
+            ),
+            (
+                SensorData::from(ProximityU16::new(ScalarData::new(2_047))),
+                [0x62, ValueType::UInt16 as u8],
+                2,
+            ),
+            (
+                SensorData::from(DistanceU16::new(ScalarData::new(1_234))),
+                [0x63, ValueType::UInt16 as u8],
+                2,
+            ),
+            (
+                SensorData::from(DistanceZonesU16::new(ArrayData::new(zones))),
+                [0x64, ValueType::UInt16 as u8],
+                64 * 2,
+            ),
+        ] {
+            assert_eq!(assert_round_trip(&value, header), size);
+        }
+    }
+
+    #[test]
+    fn test_wide_imu_types() {
+        use types::{
+            AccelerometerF32, AccelerometerI32, GyroscopeF32, GyroscopeI32, MagnetometerF32,
+            MagnetometerI32,
+        };
+
+        for value_type in [ValueType::SInt16, ValueType::SInt32, ValueType::Float32] {
+            for id in [0x42, 0x43, 0x45] {
+                assert_eq!(SensorData::components(id, value_type), Ok(3));
+            }
+        }
+        assert!(SensorData::components(0x42, ValueType::Float64).is_err());
+
+        assert_eq!(SensorIds::ACCELEROMETERI32.id(), 0x42);
+        assert_eq!(SensorIds::ACCELEROMETERI32.value_type(), ValueType::SInt32);
+        assert_eq!(SensorIds::ACCELEROMETERF32.value_type(), ValueType::Float32);
+        assert_ne!(SensorIds::GYROSCOPEI16, SensorIds::GYROSCOPEI32);
+        assert_ne!(SensorIds::MAGNETOMETERI32, SensorIds::MAGNETOMETERF32);
+
+        for (value, header) in [
+            (
+                SensorData::from(AccelerometerI32::new(Vector3Data::new(
+                    1, -524_288, 524_287,
+                ))),
+                [0x42, ValueType::SInt32 as u8],
+            ),
+            (
+                SensorData::from(AccelerometerF32::new(Vector3Data::new(0.1, -9.81, 0.0))),
+                [0x42, ValueType::Float32 as u8],
+            ),
+            (
+                SensorData::from(MagnetometerI32::new(Vector3Data::new(-3, 200_000, 7))),
+                [0x43, ValueType::SInt32 as u8],
+            ),
+            (
+                SensorData::from(MagnetometerF32::new(Vector3Data::new(0.25, -0.5, 0.0))),
+                [0x43, ValueType::Float32 as u8],
+            ),
+            (
+                SensorData::from(GyroscopeI32::new(Vector3Data::new(100_000, 0, -1))),
+                [0x45, ValueType::SInt32 as u8],
+            ),
+            (
+                SensorData::from(GyroscopeF32::new(Vector3Data::new(0.01, 3.0, -1.5))),
+                [0x45, ValueType::Float32 as u8],
+            ),
+        ] {
+            assert_eq!(assert_round_trip(&value, header), 3 * 4);
+        }
+    }
+
+    #[test]
+    fn test_fixed_point_types() {
+        use types::{AccelerometerQ16_16, AltitudeQ32_32, TemperatureQ8_8};
+
+        assert_eq!(SensorData::components(0x44, ValueType::Q8_8), Ok(1));
+        assert_eq!(SensorData::components(0x42, ValueType::Q16_16), Ok(3));
+        assert_eq!(SensorData::components(0x48, ValueType::Q32_32), Ok(1));
+
+        for (value, header, size) in [
+            (
+                SensorData::from(TemperatureQ8_8::new(ScalarData::new(Q8_8::from_f32(21.5)))),
+                [0x44, ValueType::Q8_8 as u8],
+                2,
+            ),
+            (
+                SensorData::from(AccelerometerQ16_16::new(Vector3Data::new(
+                    Q16_16::from_f32(0.1),
+                    Q16_16::from_f32(-9.81),
+                    Q16_16::from_bits(0),
+                ))),
+                [0x42, ValueType::Q16_16 as u8],
+                3 * 4,
+            ),
+            (
+                SensorData::from(AltitudeQ32_32::new(ScalarData::new(Q32_32::from_f64(
+                    519.125,
+                )))),
+                [0x48, ValueType::Q32_32 as u8],
+                8,
+            ),
+        ] {
+            assert_eq!(assert_round_trip(&value, header), size);
+        }
+    }
+
+    #[test]
+    fn test_wide_value_types() {
+        use types::{
+            EnergyI64, EulerAnglesF64, EventCounterU128, EventCounterU64, OrientationQuaternionF64,
+            TimestampI128, TimestampU64,
+        };
+
+        assert_eq!(SensorData::components(0xF0, ValueType::Float64), Ok(3));
+        assert_eq!(SensorData::components(0xF1, ValueType::Float64), Ok(4));
+
+        for (value, header, size) in [
+            (
+                SensorData::from(EventCounterU64::new(ScalarData::new(u64::MAX))),
+                [0x3, ValueType::UInt64 as u8],
+                8,
+            ),
+            (
+                SensorData::from(EventCounterU128::new(ScalarData::new(u128::MAX))),
+                [0x3, ValueType::UInt128 as u8],
+                16,
+            ),
+            (
+                SensorData::from(TimestampU64::new(ScalarData::new(1_234_567_890_123))),
+                [0x4, ValueType::UInt64 as u8],
+                8,
+            ),
+            (
+                SensorData::from(TimestampI128::new(ScalarData::new(-1))),
+                [0x4, ValueType::SInt128 as u8],
+                16,
+            ),
+            (
+                SensorData::from(EnergyI64::new(ScalarData::new(-42_000_000_000))),
+                [0x14, ValueType::SInt64 as u8],
+                8,
+            ),
+            (
+                SensorData::from(EulerAnglesF64::new(Vector3Data::new(0.1, -0.2, 0.3))),
+                [0xF0, ValueType::Float64 as u8],
+                3 * 8,
+            ),
+            (
+                SensorData::from(OrientationQuaternionF64::new(Vector4Data::new(
+                    1.0, 0.0, 0.0, 0.0,
+                ))),
+                [0xF1, ValueType::Float64 as u8],
+                4 * 8,
+            ),
+        ] {
+            assert_eq!(assert_round_trip(&value, header), size);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_max_encoded_len() {
+        use types::{DistanceZonesU16, Identification};
+
+        assert_eq!(AccelerometerI16::MAX_ENCODED_LEN, 6);
+        assert_eq!(AccelerometerI16::ENCODED_LEN, 6);
+        assert_eq!(DistanceZonesU16::ENCODED_LEN, 128);
+        assert_eq!(Identification::MAX_ENCODED_LEN, 4 + 1 + 64);
+        assert_eq!(SensorData::MAX_ENCODED_LEN, 2 + 64 * 2);
+        assert_eq!(
+            VersionedDataFrame::<Version1, Version1DataFrame>::MAX_ENCODED_LEN,
+            1 + 18 + SensorData::MAX_ENCODED_LEN
+        );
+
+        let mut buffer = [0_u8; 256];
+        for value in [
+            SensorData::from(AccelerometerI16::new(Vector3Data::new(1, -2, 3))),
+            SensorData::from(Identification::new(Identifier::new(
+                SensorId::default(),
+                IdentifierCode::Product,
+                "ABCDEFGH",
+            ))),
+            SensorData::from(DistanceZonesU16::new(ArrayData::new([0xFFFF; 64]))),
+            SensorData::vendor(0xC0, ValueType::UInt8, &[0xFF; 64]).expect("Invalid vendor data"),
+        ] {
+            let frame = Version1DataFrame::new(u32::MAX, 999, 999, u32::MAX, u32::MAX, 1, value);
+            let num_serialized = bincode::encode_into_slice(
+                frame.into_versioned(),
+                &mut buffer,
+                SERIALIZATION_CONFIG,
+            )
+            .expect("Failed to serialize");
+            assert!(
+                num_serialized
+                    <= VersionedDataFrame::<Version1, Version1DataFrame>::MAX_ENCODED_LEN
+            );
+        }
+    }
 }
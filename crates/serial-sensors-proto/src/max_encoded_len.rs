@@ -0,0 +1,81 @@
+use crate::Checksum;
+
+/// Provides an upper bound of the number of bytes a value occupies when serialized.
+///
+/// The bound refers to the serialized data before byte stuffing is applied; use
+/// [`max_wire_len`] to determine the number of bytes on the wire. Since it is a constant,
+/// it can be used to size buffers at compile time.
+pub trait MaxEncodedLen {
+    /// The maximum number of serialized bytes.
+    const MAX_ENCODED_LEN: usize;
+}
+
+/// Returns the maximum number of bytes on the wire for a frame of the specified serialized
+/// length, including the checksum trailer, the byte stuffing overhead and the delimiter.
+///
+/// ```
+/// # use serial_sensors_proto::{max_wire_len, Checksum, MaxEncodedLen, VersionedDataFrame};
+/// # use serial_sensors_proto::versions::{Version1, Version1DataFrame};
+/// type Frame = VersionedDataFrame<Version1, Version1DataFrame>;
+/// let buffer = [0_u8; max_wire_len(Frame::MAX_ENCODED_LEN, Checksum::Crc16)];
+/// # assert_eq!(buffer.len(), 153);
+/// ```
+#[must_use]
+pub const fn max_wire_len(serialized_len: usize, checksum: Checksum) -> usize {
+    corncobs::max_encoded_len(serialized_len + checksum.len())
+}
+
+/// Implements [`MaxEncodedLen`] for primitive types, which are encoded with a fixed size.
+macro_rules! impl_max_encoded_len {
+    ($($type:ty),+) => {
+        $(
+            impl MaxEncodedLen for $type {
+                const MAX_ENCODED_LEN: usize = core::mem::size_of::<$type>();
+            }
+        )+
+    };
+}
+
+impl_max_encoded_len!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64, bool);
+
+impl<T, const N: usize> MaxEncodedLen for [T; N]
+where
+    T: MaxEncodedLen,
+{
+    const MAX_ENCODED_LEN: usize = N * T::MAX_ENCODED_LEN;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serializer::SERIALIZATION_CONFIG;
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_primitive_lengths() {
+        let mut buffer = [0_u8; 32];
+
+        let num_serialized =
+            bincode::encode_into_slice(u128::MAX, &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to serialize");
+        assert_eq!(num_serialized, u128::MAX_ENCODED_LEN);
+
+        let num_serialized = bincode::encode_into_slice(true, &mut buffer, SERIALIZATION_CONFIG)
+            .expect("Failed to serialize");
+        assert_eq!(num_serialized, bool::MAX_ENCODED_LEN);
+
+        let num_serialized =
+            bincode::encode_into_slice([1.0_f32; 4], &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to serialize");
+        assert_eq!(num_serialized, <[f32; 4]>::MAX_ENCODED_LEN);
+    }
+
+    #[test]
+    fn test_max_wire_len() {
+        assert_eq!(max_wire_len(0, Checksum::None), 2);
+        assert_eq!(max_wire_len(28, Checksum::None), 30);
+        assert_eq!(max_wire_len(28, Checksum::Crc32), 34);
+        assert_eq!(max_wire_len(254, Checksum::None), 256);
+        assert_eq!(max_wire_len(255, Checksum::None), 258);
+    }
+}
@@ -0,0 +1,562 @@
+use crate::versions::{AnyVersionFrame, CompactDecoder, Version1DataFrame, Version2DataFrame};
+use crate::{
+    Components, DataFrame, DeserializationError, IdentifierCode, LinearRanges, SensorData, SensorId,
+};
+use std::collections::BTreeMap;
+
+/// The number of sequence steps by which a frame may lag behind the latest one and still be
+/// considered out of order, rather than originating from a restarted device.
+const REORDER_WINDOW: u32 = 256;
+
+/// Tracks the metadata of the sensors on the other end of the wire and uses it to convert
+/// raw readings into physical values.
+///
+/// Every frame is passed to [`SensorRegistry::ingest`]. [`LinearRanges`] and
+/// [`Identification`](crate::types::Identification) frames update the entry of the sensor they
+/// target; repeated metadata simply refreshes it. Metadata that is older than what was already
+/// accepted, according to its global sequence, is ignored. Sensors that were never described
+/// are tracked as well, and readings of sensors whose ranges are not yet known are reported
+/// unscaled until the metadata arrives.
+///
+/// A frame whose global sequence jumps back far behind the latest frame of the link indicates
+/// that the device restarted; the metadata of all sensors is then discarded. A device that
+/// restarts shortly after its previous start cannot be told apart from frames arriving out of
+/// order, so its outdated metadata is kept until it is repeated with a later global sequence;
+/// call [`SensorRegistry::clear`] when the device is known to have restarted.
+///
+/// ```
+/// # use serial_sensors_proto::{LinearRanges, SensorId, SensorRegistry, Vector3Data};
+/// # use serial_sensors_proto::types::{AccelerometerI16, LinearRangeInfo};
+/// # use serial_sensors_proto::versions::Version1DataFrame;
+/// let value = AccelerometerI16::new(Vector3Data { x: 16384, y: 0, z: -8192 });
+/// let reading = Version1DataFrame::new(0, 0, 0, 2, 1, 7, value);
+///
+/// let ranges = LinearRanges {
+///     target: SensorId::from(&reading),
+///     resolution_bits: 16,
+///     scale: 16384,
+///     ..Default::default()
+/// };
+/// let meta = Version1DataFrame::new(0, 0, 0, 1, 1, 0, LinearRangeInfo::new(ranges));
+///
+/// let mut registry = SensorRegistry::new();
+/// assert!(registry.ingest(&meta).is_none());
+///
+/// let converted = registry.ingest(&reading).unwrap();
+/// assert!(converted.scaled);
+/// assert_eq!(converted.values, [1.0, 0.0, -0.5]);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SensorRegistry {
+    sensors: BTreeMap<SensorId, SensorInfo>,
+    /// The latest global sequence received from any frame.
+    latest_sequence: Option<u32>,
+    /// Restores the headers of compact frames.
+    compact: CompactDecoder,
+}
+
+/// The metadata known about a single sensor.
+#[derive(Debug, Default, Clone)]
+pub struct SensorInfo {
+    /// The latest value ranges and the global sequence they were received with.
+    ranges: Option<(u32, LinearRanges)>,
+    /// The latest identifiers and the global sequence they were received with.
+    identifiers: BTreeMap<IdentifierCode, (u32, String)>,
+    /// The number of readings received.
+    readings: u64,
+}
+
+/// A sensor reading converted into physical values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvertedReading {
+    /// The sensor the reading originates from.
+    pub target: SensorId,
+    /// The sensor sequence identifier of the reading.
+    pub sensor_sequence: u32,
+    /// The value of each component.
+    pub values: Vec<f32>,
+    /// Indicates whether the values were scaled using the sensor's [`LinearRanges`].
+    /// If no ranges are known yet, the raw values are reported.
+    pub scaled: bool,
+}
+
+impl SensorRegistry {
+    /// Creates a new, empty registry.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            sensors: BTreeMap::new(),
+            latest_sequence: None,
+            compact: CompactDecoder::new(),
+        }
+    }
+
+    /// Ingests a version 1 frame.
+    ///
+    /// Metadata frames update the registry and return `None`. Readings are returned converted
+    /// into physical values; readings that cannot be represented as numbers, such as vendor
+    /// payloads or battery states, return `None`.
+    pub fn ingest(&mut self, frame: &Version1DataFrame) -> Option<ConvertedReading> {
+        self.ingest_value(
+            frame.target(),
+            frame.global_sequence,
+            frame.sensor_sequence,
+            &frame.value,
+        )
+    }
+
+    /// Ingests a frame of any supported protocol version and kind. See [`SensorRegistry::ingest`].
+    ///
+    /// Returns the converted readings of the frame; batches are expanded into their samples,
+    /// and the headers of compact frames are restored using the preceding compact frames.
+    ///
+    /// ## Errors
+    /// Returns an error if the samples of a batch cannot be decoded, in which case the samples
+    /// preceding the erroneous one are ingested nevertheless, or if the header of a compact
+    /// frame cannot be restored before the next keyframe of its sensor.
+    pub fn ingest_any(
+        &mut self,
+        frame: &AnyVersionFrame,
+    ) -> Result<Vec<ConvertedReading>, DeserializationError> {
+        let mut readings = Vec::new();
+        match frame {
+            AnyVersionFrame::Version1(frame) => readings.extend(self.ingest(&frame.data)),
+            AnyVersionFrame::Version2(frame) => readings.extend(self.ingest_v2(&frame.data)),
+            AnyVersionFrame::Batch(batch) => {
+                readings.reserve(batch.data.len());
+                for frame in batch.data.frames() {
+                    readings.extend(self.ingest_v2(&frame?));
+                }
+            }
+            AnyVersionFrame::Compact(frame) => {
+                let frame = self.compact.decode(frame.data.clone())?;
+                readings.extend(self.ingest_v2(&frame));
+            }
+        }
+        Ok(readings)
+    }
+
+    /// Returns the metadata known about the specified sensor.
+    #[must_use]
+    pub fn sensor(&self, id: &SensorId) -> Option<&SensorInfo> {
+        self.sensors.get(id)
+    }
+
+    /// Returns all sensors seen so far, ordered by their [`SensorId`].
+    pub fn sensors(&self) -> impl Iterator<Item = (&SensorId, &SensorInfo)> {
+        self.sensors.iter()
+    }
+
+    /// Forgets all sensors, e.g. after the device was reconnected.
+    pub fn clear(&mut self) {
+        self.sensors.clear();
+        self.latest_sequence = None;
+        self.compact.reset();
+    }
+
+    /// Converts a reading of the specified sensor using the ranges known for it.
+    ///
+    /// Returns `None` if the reading cannot be represented as numbers. If the ranges are
+    /// unknown or cannot be applied, e.g. due to an unknown [`ScaleOp`](crate::ScaleOp) or a
+    /// zero scale, the raw values are returned.
+    #[must_use]
+    pub fn convert(&self, target: &SensorId, value: &SensorData) -> Option<(Vec<f32>, bool)> {
+        let values = raw_components(value)?;
+        let Some(ranges) = self.sensor(target).and_then(SensorInfo::ranges) else {
+            return Some((values, false));
+        };
+
+        let converted: Result<Vec<f32>, _> =
+            values.iter().map(|&value| ranges.convert(value)).collect();
+        match converted {
+            Ok(converted) => Some((converted, true)),
+            Err(_) => Some((values, false)),
+        }
+    }
+
+    /// Ingests a version 2 frame.
+    fn ingest_v2(&mut self, frame: &Version2DataFrame) -> Option<ConvertedReading> {
+        self.ingest_value(
+            frame.target(),
+            frame.global_sequence,
+            frame.sensor_sequence,
+            &frame.value,
+        )
+    }
+
+    fn ingest_value(
+        &mut self,
+        target: SensorId,
+        global_sequence: u32,
+        sensor_sequence: u32,
+        value: &SensorData,
+    ) -> Option<ConvertedReading> {
+        self.track_sequence(global_sequence);
+
+        let sensor = self.sensors.entry(target.clone()).or_default();
+        match value {
+            SensorData::LinearRanges(ranges) => {
+                if is_current(global_sequence, sensor.ranges.as_ref().map(|(seq, _)| *seq)) {
+                    sensor.ranges = Some((global_sequence, ranges.inner().clone()));
+                }
+                None
+            }
+            SensorData::Identification(identifier) => {
+                let previous = sensor
+                    .identifiers
+                    .get(&identifier.code)
+                    .map(|(seq, _)| *seq);
+                if is_current(global_sequence, previous) {
+                    let value = String::from_utf8_lossy(&identifier.value);
+                    let value = value.trim_end_matches([' ', '\0']).into();
+                    sensor
+                        .identifiers
+                        .insert(identifier.code, (global_sequence, value));
+                }
+                None
+            }
+            _ => {
+                sensor.readings += 1;
+                let (values, scaled) = self.convert(&target, value)?;
+                Some(ConvertedReading {
+                    target,
+                    sensor_sequence,
+                    values,
+                    scaled,
+                })
+            }
+        }
+    }
+
+    /// Tracks the latest global sequence of the link and discards all metadata when the
+    /// sequence jumps back beyond the [`REORDER_WINDOW`].
+    fn track_sequence(&mut self, global_sequence: u32) {
+        if global_sequence == u32::MAX {
+            return;
+        }
+
+        let lag = self
+            .latest_sequence
+            .map_or(0, |latest| latest.wrapping_sub(global_sequence));
+        if lag > REORDER_WINDOW && lag <= u32::MAX / 2 {
+            for sensor in self.sensors.values_mut() {
+                sensor.ranges = None;
+                sensor.identifiers.clear();
+            }
+        }
+        if lag == 0 || lag > REORDER_WINDOW {
+            self.latest_sequence = Some(global_sequence);
+        }
+    }
+}
+
+impl SensorInfo {
+    /// Returns the latest value ranges of the sensor.
+    #[must_use]
+    pub fn ranges(&self) -> Option<&LinearRanges> {
+        self.ranges.as_ref().map(|(_, ranges)| ranges)
+    }
+
+    /// Returns the latest identifier of the specified type, without trailing padding.
+    #[must_use]
+    pub fn identifier(&self, code: IdentifierCode) -> Option<&str> {
+        self.identifiers.get(&code).map(|(_, value)| value.as_str())
+    }
+
+    /// Returns all identifiers of the sensor.
+    pub fn identifiers(&self) -> impl Iterator<Item = (IdentifierCode, &str)> {
+        self.identifiers
+            .iter()
+            .map(|(code, (_, value))| (*code, value.as_str()))
+    }
+
+    /// Returns the number of readings received from the sensor.
+    #[must_use]
+    pub fn readings(&self) -> u64 {
+        self.readings
+    }
+}
+
+/// Determines whether metadata received with the specified global sequence supersedes the
+/// metadata received with the previous sequence.
+///
+/// Frames without sequence information are always accepted. Frames that lag behind by more
+/// than [`REORDER_WINDOW`] are assumed to originate from a restarted device.
+fn is_current(sequence: u32, previous: Option<u32>) -> bool {
+    match previous {
+        None => true,
+        Some(_) if sequence == u32::MAX => true,
+        Some(previous) => previous.wrapping_sub(sequence).wrapping_sub(1) >= REORDER_WINDOW,
+    }
+}
+
+/// Extracts the components of a numeric reading.
+fn raw_components(value: &SensorData) -> Option<Vec<f32>> {
+    (0..usize::from(value.num_components()))
+        .map(|index| value.component(index))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        AccelerometerI16, BatteryStateInfo, Identification, LinearRangeInfo, MagnetometerI32,
+        PressureF32, TemperatureQ8_8,
+    };
+    use crate::versions::{BatchDataFrame, CompactEncoder, TimeBase};
+    use crate::{BatteryState, Identifier, ScalarData, ValueType, Vector3Data, Q8_8};
+
+    fn accelerometer(global_sequence: u32) -> Version1DataFrame {
+        let value = AccelerometerI16::new(Vector3Data {
+            x: 16384,
+            y: -8192,
+            z: 0,
+        });
+        Version1DataFrame::new(0, 0, 0, global_sequence, global_sequence, 3, value)
+    }
+
+    fn ranges(global_sequence: u32, scale: i32) -> Version1DataFrame {
+        let value = LinearRangeInfo::new(LinearRanges {
+            target: SensorId::from(&accelerometer(0)),
+            resolution_bits: 16,
+            scale,
+            ..Default::default()
+        });
+        Version1DataFrame::new(0, 0, 0, global_sequence, 0, 0, value)
+    }
+
+    fn identification(global_sequence: u32, code: IdentifierCode, text: &str) -> Version1DataFrame {
+        let target = SensorId::from(&accelerometer(0));
+        let value = Identification::new(Identifier::new(target, code, text));
+        Version1DataFrame::new(0, 0, 0, global_sequence, 0, 0, value)
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_metadata_after_readings() {
+        let mut registry = SensorRegistry::new();
+
+        let converted = registry.ingest(&accelerometer(1)).unwrap();
+        assert!(!converted.scaled);
+        assert_eq!(converted.values, [16384.0, -8192.0, 0.0]);
+
+        assert!(registry.ingest(&ranges(2, 16384)).is_none());
+        let converted = registry.ingest(&accelerometer(3)).unwrap();
+        assert!(converted.scaled);
+        assert_eq!(converted.values, [1.0, -0.5, 0.0]);
+        assert_eq!(converted.sensor_sequence, 3);
+
+        let info = registry.sensor(&converted.target).unwrap();
+        assert_eq!(info.readings(), 2);
+
+        // Ranges that cannot be applied leave the readings unscaled.
+        assert!(registry.ingest(&ranges(4, 0)).is_none());
+        let converted = registry.ingest(&accelerometer(5)).unwrap();
+        assert!(!converted.scaled);
+        assert_eq!(converted.values, [16384.0, -8192.0, 0.0]);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_out_of_order_metadata() {
+        let mut registry = SensorRegistry::new();
+        registry.ingest(&ranges(10, 8192));
+        registry.ingest(&ranges(9, 16384));
+        assert_eq!(
+            registry.ingest(&accelerometer(11)).unwrap().values,
+            [2.0, -1.0, 0.0]
+        );
+
+        // Periodically repeated metadata refreshes the entry.
+        registry.ingest(&ranges(12, 8192));
+        registry.ingest(&ranges(20, 16384));
+        assert_eq!(
+            registry.ingest(&accelerometer(21)).unwrap().values,
+            [1.0, -0.5, 0.0]
+        );
+
+        // A restarted device starts over with its sequence numbers.
+        registry.ingest(&ranges(1000, 16384));
+        registry.ingest(&ranges(0, 4096));
+        assert_eq!(
+            registry.ingest(&accelerometer(1)).unwrap().values,
+            [4.0, -2.0, 0.0]
+        );
+
+        // Frames without sequence numbers are always applied.
+        registry.ingest(&ranges(u32::MAX, 16384));
+        assert_eq!(
+            registry.ingest(&accelerometer(2)).unwrap().values,
+            [1.0, -0.5, 0.0]
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_restart() {
+        let mut registry = SensorRegistry::new();
+        registry.ingest(&ranges(5, 16384));
+        registry.ingest(&identification(5, IdentifierCode::Product, "LSM303"));
+        for sequence in 6..300 {
+            assert!(registry.ingest(&accelerometer(sequence)).unwrap().scaled);
+        }
+
+        // The device restarts and sends its metadata again, with lower sequence numbers.
+        registry.ingest(&ranges(2, 4096));
+        assert_eq!(
+            registry.ingest(&accelerometer(3)).unwrap().values,
+            [4.0, -2.0, 0.0]
+        );
+
+        let info = registry.sensor(&SensorId::from(&accelerometer(0))).unwrap();
+        assert_eq!(info.identifier(IdentifierCode::Product), None);
+        assert_eq!(info.readings(), 295);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_early_restart() {
+        let mut registry = SensorRegistry::new();
+        registry.ingest(&ranges(5, 16384));
+        registry.ingest(&accelerometer(100));
+
+        // A restart within the reorder window looks like frames arriving out of order,
+        // so the outdated ranges are kept.
+        registry.ingest(&ranges(2, 4096));
+        assert_eq!(
+            registry.ingest(&accelerometer(3)).unwrap().values,
+            [1.0, -0.5, 0.0]
+        );
+
+        // Repeated metadata with a later global sequence supersedes them.
+        registry.ingest(&ranges(6, 4096));
+        assert_eq!(
+            registry.ingest(&accelerometer(7)).unwrap().values,
+            [4.0, -2.0, 0.0]
+        );
+
+        // Clearing the registry accepts the metadata right away.
+        registry.ingest(&accelerometer(200));
+        registry.clear();
+        registry.ingest(&ranges(1, 8192));
+        assert_eq!(
+            registry.ingest(&accelerometer(2)).unwrap().values,
+            [2.0, -1.0, 0.0]
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_identification() {
+        let mut registry = SensorRegistry::new();
+        registry.ingest(&identification(
+            1,
+            IdentifierCode::Maker,
+            "STMicroelectronics",
+        ));
+        registry.ingest(&identification(3, IdentifierCode::Product, "LSM303DLHC"));
+        registry.ingest(&identification(2, IdentifierCode::Product, "LSM303"));
+
+        let target = SensorId::from(&accelerometer(0));
+        let (id, info) = registry.sensors().next().unwrap();
+        assert_eq!(id, &target);
+        assert_eq!(info.readings(), 0);
+        assert!(info.ranges().is_none());
+        assert_eq!(
+            info.identifier(IdentifierCode::Maker),
+            Some("STMicroelectronics")
+        );
+        assert_eq!(info.identifier(IdentifierCode::Product), Some("LSM303DLHC"));
+        assert_eq!(info.identifier(IdentifierCode::Revision), None);
+        assert_eq!(info.identifiers().count(), 2);
+
+        registry.clear();
+        assert!(registry.sensor(&target).is_none());
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_value_types() {
+        let mut registry = SensorRegistry::new();
+
+        let value = MagnetometerI32::new(Vector3Data {
+            x: -70_000,
+            y: 1,
+            z: 0,
+        });
+        let frame = Version1DataFrame::new(0, 0, 0, 1, 1, 0, value);
+        assert_eq!(
+            registry.ingest(&frame).unwrap().values,
+            [-70_000.0, 1.0, 0.0]
+        );
+
+        let value = PressureF32::new(ScalarData { value: 1013.25 });
+        let frame = Version2DataFrame::from(Version1DataFrame::new(0, 0, 0, 2, 1, 0, value));
+        let frame = AnyVersionFrame::Version2(frame.into());
+        assert_eq!(registry.ingest_any(&frame).unwrap()[0].values, [1013.25]);
+
+        let value = TemperatureQ8_8::new(ScalarData::new(Q8_8::from_f32(21.5)));
+        let frame = Version1DataFrame::new(0, 0, 0, 3, 1, 0, value);
+        assert_eq!(registry.ingest(&frame).unwrap().values, [21.5]);
+
+        let value = SensorData::vendor(0xC0, ValueType::UInt8, &[1, 2, 3]).unwrap();
+        let frame = Version1DataFrame::new(0, 0, 0, 4, 1, 0, value);
+        assert!(registry.ingest(&frame).is_none());
+
+        let value = BatteryStateInfo::new(BatteryState::default());
+        let frame = Version1DataFrame::new(0, 0, 0, 5, 1, 0, value);
+        assert!(registry.ingest(&frame).is_none());
+        assert_eq!(registry.sensors().count(), 5);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_ingest_batch() {
+        let mut registry = SensorRegistry::new();
+        registry.ingest(&ranges(1, 16384));
+
+        let mut batch = BatchDataFrame::new(0, TimeBase::BootRelative, 1_000, 2, 10, 3);
+        for x in [16384, 8192] {
+            batch
+                .push(AccelerometerI16::new(Vector3Data { x, y: 0, z: 0 }))
+                .unwrap();
+        }
+        let frame = AnyVersionFrame::Batch(batch.into_versioned());
+
+        let readings = registry.ingest_any(&frame).unwrap();
+        assert_eq!(readings.len(), 2);
+        assert_eq!(readings[0].values, [1.0, 0.0, 0.0]);
+        assert_eq!(readings[1].values, [0.5, 0.0, 0.0]);
+        assert_eq!(readings[1].sensor_sequence, 11);
+
+        let info = registry.sensor(&readings[0].target).unwrap();
+        assert_eq!(info.readings(), 2);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_ingest_compact() {
+        let mut registry = SensorRegistry::new();
+        registry.ingest(&ranges(1, 16384));
+
+        let mut encoder: CompactEncoder = CompactEncoder::new(0);
+        let mut compact = |sequence: u32| {
+            let frame = Version2DataFrame::from(accelerometer(sequence));
+            AnyVersionFrame::Compact(encoder.encode(frame).into_versioned())
+        };
+        let (first, second, third) = (compact(2), compact(3), compact(4));
+
+        let readings = registry.ingest_any(&first).unwrap();
+        assert_eq!(readings[0].values, [1.0, -0.5, 0.0]);
+        let readings = registry.ingest_any(&second).unwrap();
+        assert_eq!(readings[0].sensor_sequence, 3);
+
+        // Delta frames cannot be restored after the decoder state was discarded.
+        registry.clear();
+        assert!(matches!(
+            registry.ingest_any(&third),
+            Err(DeserializationError::MissingKeyframe)
+        ));
+    }
+}
@@ -0,0 +1,337 @@
+use crate::decoder::DELIMITER;
+use crate::{
+    serialize_to_writer, Checksum, DataFrame, ProtocolVersion, SerializationError,
+    VersionedDataFrame,
+};
+use bincode::Encode;
+use embedded_io::ErrorKind;
+
+/// Sends frames over an [`embedded_io::Write`] transport, such as a UART or a USB CDC-ACM port.
+///
+/// Every frame is serialized, byte-stuffed and terminated with the delimiter; partial writes
+/// of the transport are continued until the frame was written completely. If a transport error
+/// interrupted the previous frame, the next frame is preceded by a delimiter, so that receivers
+/// discard the partial frame without losing the next one.
+///
+/// ```
+/// # use serial_sensors_proto::{Checksum, FrameSender, Vector3Data};
+/// # use serial_sensors_proto::types::AccelerometerI16;
+/// # use serial_sensors_proto::versions::Version1DataFrame;
+/// let mut buffer = [0_u8; 64];
+/// let mut sender = FrameSender::with_checksum(&mut buffer[..], Checksum::Crc16);
+///
+/// let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+/// let frame = Version1DataFrame::new(0, 0, 0, u32::MAX, 12, 0, value);
+/// let written = sender.send(frame).unwrap();
+/// assert_eq!(written, 31);
+/// ```
+#[derive(Debug)]
+pub struct FrameSender<W> {
+    writer: W,
+    checksum: Checksum,
+    /// Indicates whether the previous frame may have been sent partially.
+    interrupted: bool,
+}
+
+impl<W> FrameSender<W>
+where
+    W: embedded_io::Write,
+{
+    /// Creates a new sender that does not append a checksum.
+    pub const fn new(writer: W) -> Self {
+        Self::with_checksum(writer, Checksum::None)
+    }
+
+    /// Creates a new sender that appends the specified [`Checksum`] to every frame.
+    pub const fn with_checksum(writer: W, checksum: Checksum) -> Self {
+        Self {
+            writer,
+            checksum,
+            interrupted: false,
+        }
+    }
+
+    /// Serializes and sends a frame, then flushes the transport.
+    ///
+    /// Returns the number of bytes written, including the delimiters.
+    ///
+    /// ## Errors
+    /// Returns an error when serialization failed or the transport reported an error.
+    /// In the latter case, a partial frame may have been sent; it is terminated by a delimiter
+    /// before the next frame.
+    pub fn send<I, V, D>(&mut self, frame: I) -> Result<usize, SerializationError>
+    where
+        I: Into<VersionedDataFrame<V, D>>,
+        V: ProtocolVersion,
+        D: DataFrame + Encode,
+    {
+        let mut written = 0;
+        if self.interrupted {
+            match self.writer.write(&[DELIMITER]) {
+                Ok(0) => return Err(SerializationError::Io(ErrorKind::WriteZero.into())),
+                Ok(_) => written += 1,
+                Err(err) => {
+                    return Err(SerializationError::Io(
+                        embedded_io::Error::kind(&err).into(),
+                    ))
+                }
+            }
+        }
+
+        self.interrupted = true;
+        written += serialize_to_writer(frame, self.checksum, &mut self.writer)?;
+        self.interrupted = false;
+
+        self.writer
+            .flush()
+            .map_err(|err| SerializationError::Io(embedded_io::Error::kind(&err).into()))?;
+        Ok(written)
+    }
+
+    /// Returns a reference to the transport.
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the sender and returns the transport.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(feature = "embedded-io-async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "embedded-io-async")))]
+mod asynchronous {
+    use crate::decoder::DELIMITER;
+    use crate::versions::Version2DataFrame;
+    use crate::{
+        serialize_with_checksum, Checksum, DataFrame, ProtocolVersion, SerializationError,
+        VersionedDataFrame,
+    };
+    use bincode::Encode;
+    use embedded_io::ErrorKind;
+
+    /// Sends frames over an [`embedded_io_async::Write`] transport.
+    ///
+    /// Since serialization itself is synchronous, every frame is prepared in an internal buffer
+    /// of `N` bytes before it is written; the default is large enough for any version 1 or
+    /// version 2 frame. Partial writes of the transport are continued until the frame was
+    /// written completely.
+    ///
+    /// If a transport error or a dropped [`send`](Self::send) future interrupted the previous
+    /// frame, the next frame is preceded by a delimiter, so that receivers discard the partial
+    /// frame without losing the next one.
+    #[derive(Debug)]
+    pub struct AsyncFrameSender<W, const N: usize = { Version2DataFrame::MAX_SERIALIZE_BUFFER_LEN }> {
+        writer: W,
+        checksum: Checksum,
+        buffer: [u8; N],
+        /// Indicates whether the previous frame may have been sent partially.
+        interrupted: bool,
+    }
+
+    impl<W, const N: usize> AsyncFrameSender<W, N>
+    where
+        W: embedded_io_async::Write,
+    {
+        /// Creates a new sender that does not append a checksum.
+        pub const fn new(writer: W) -> Self {
+            Self::with_checksum(writer, Checksum::None)
+        }
+
+        /// Creates a new sender that appends the specified [`Checksum`] to every frame.
+        pub const fn with_checksum(writer: W, checksum: Checksum) -> Self {
+            Self {
+                writer,
+                checksum,
+                buffer: [0; N],
+                interrupted: false,
+            }
+        }
+
+        /// Serializes and sends a frame, then flushes the transport.
+        ///
+        /// Returns the number of bytes written, including the delimiters.
+        ///
+        /// ## Errors
+        /// Returns an error when serialization failed or the transport reported an error.
+        /// In the latter case, a partial frame may have been sent; it is terminated by a
+        /// delimiter before the next frame.
+        pub async fn send<I, V, D>(&mut self, frame: I) -> Result<usize, SerializationError>
+        where
+            I: Into<VersionedDataFrame<V, D>>,
+            V: ProtocolVersion,
+            D: DataFrame + Encode,
+        {
+            let range = serialize_with_checksum(frame, self.checksum, &mut self.buffer)?;
+            let mut written = range.len();
+
+            if self.interrupted {
+                write_all(&mut self.writer, &[DELIMITER]).await?;
+                written += 1;
+            }
+
+            self.interrupted = true;
+            write_all(&mut self.writer, &self.buffer[range]).await?;
+            self.interrupted = false;
+
+            self.writer
+                .flush()
+                .await
+                .map_err(|err| SerializationError::Io(embedded_io::Error::kind(&err).into()))?;
+            Ok(written)
+        }
+
+        /// Returns a reference to the transport.
+        pub fn writer(&self) -> &W {
+            &self.writer
+        }
+
+        /// Consumes the sender and returns the transport.
+        pub fn into_inner(self) -> W {
+            self.writer
+        }
+    }
+
+    /// Writes all bytes, continuing partial writes of the transport.
+    async fn write_all<W>(writer: &mut W, mut data: &[u8]) -> Result<(), SerializationError>
+    where
+        W: embedded_io_async::Write,
+    {
+        while !data.is_empty() {
+            match writer.write(data).await {
+                Ok(0) => return Err(SerializationError::Io(ErrorKind::WriteZero.into())),
+                Ok(count) => data = &data[count..],
+                Err(err) => {
+                    return Err(SerializationError::Io(
+                        embedded_io::Error::kind(&err).into(),
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-io-async")]
+pub use asynchronous::AsyncFrameSender;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{frame, Trickle};
+    use crate::{deserialize, FrameDecoder, IoError};
+    use embedded_io::ErrorKind;
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_send() {
+        let mut sender = FrameSender::with_checksum(Trickle::new(), Checksum::Crc32);
+        let written = sender.send(frame(12)).unwrap();
+        assert_eq!(written, 33);
+
+        let mut transport = sender.into_inner();
+        assert!(transport.flushed);
+        assert_eq!(transport.len, written);
+        assert_eq!(transport.buffer[written - 1], 0);
+
+        let (_read, data) = deserialize(&mut transport.buffer[..written]).unwrap();
+        assert_eq!(data.data, frame(12));
+    }
+
+    #[test]
+    fn test_send_error() {
+        let mut sender = FrameSender::new(Trickle::new());
+        assert!(sender.send(frame(12)).is_ok());
+        assert!(sender.send(frame(12)).is_ok());
+        assert!(matches!(
+            sender.send(frame(12)),
+            Err(SerializationError::Io(IoError::EmbeddedIo(
+                ErrorKind::OutOfMemory
+            )))
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_send_interrupted() {
+        let mut transport = Trickle::new();
+        transport.fail_at = 9;
+        let mut sender = FrameSender::new(transport);
+        assert!(matches!(
+            sender.send(frame(1)),
+            Err(SerializationError::Io(IoError::EmbeddedIo(
+                ErrorKind::ConnectionReset
+            )))
+        ));
+
+        // The partial frame is terminated before the next frame.
+        assert_eq!(sender.send(frame(2)).unwrap(), 30);
+        assert_received(&sender.into_inner());
+    }
+
+    #[test]
+    #[cfg(all(feature = "embedded-io-async", feature = "std"))]
+    #[allow(clippy::unwrap_used)]
+    fn test_send_async() {
+        let mut sender: AsyncFrameSender<_> = AsyncFrameSender::new(Trickle::new());
+        let written = block_on(sender.send(frame(12))).unwrap();
+        assert_eq!(written, 29);
+
+        let mut transport = sender.into_inner();
+        assert!(transport.flushed);
+        let (_read, data) = deserialize(&mut transport.buffer[..written]).unwrap();
+        assert_eq!(data.data, frame(12));
+    }
+
+    #[test]
+    #[cfg(all(feature = "embedded-io-async", feature = "std"))]
+    #[allow(clippy::unwrap_used)]
+    fn test_send_async_interrupted() {
+        let mut transport = Trickle::new();
+        transport.fail_at = 9;
+        let mut sender: AsyncFrameSender<_> = AsyncFrameSender::new(transport);
+        assert!(matches!(
+            block_on(sender.send(frame(1))),
+            Err(SerializationError::Io(IoError::EmbeddedIo(
+                ErrorKind::ConnectionReset
+            )))
+        ));
+
+        // The partial frame is terminated before the next frame.
+        assert_eq!(block_on(sender.send(frame(2))).unwrap(), 30);
+        assert_received(&sender.into_inner());
+    }
+
+    /// Asserts that the partial first frame is dropped and the second frame is received.
+    #[allow(clippy::unwrap_used)]
+    fn assert_received(transport: &Trickle) {
+        let mut decoder: FrameDecoder<64> = FrameDecoder::new();
+        let mut frames = decoder.decode(&transport.buffer[..transport.len]);
+        assert!(frames.next().unwrap().is_err());
+        assert_eq!(frames.next().unwrap().unwrap().data, frame(2));
+        assert!(frames.next().is_none());
+    }
+
+    /// Polls a future that completes on the first poll, as the transport never blocks.
+    #[cfg(all(feature = "embedded-io-async", feature = "std"))]
+    fn block_on<F: core::future::Future>(future: F) -> F::Output {
+        use core::pin::pin;
+        use core::task::{Context, Poll};
+        use std::sync::Arc;
+        use std::task::{Wake, Waker};
+
+        struct NoopWaker;
+
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut context = Context::from_waker(&waker);
+        let Poll::Ready(output) = pin!(future).poll(&mut context) else {
+            panic!("The transport should not block");
+        };
+        output
+    }
+}
@@ -1,5 +1,5 @@
-use crate::versions::Version1DataFrame;
-use crate::{ComponentLookupError, SensorData, ValueType};
+use crate::versions::{Version1DataFrame, Version2DataFrame};
+use crate::{ComponentLookupError, MaxEncodedLen, SensorData, ValueType};
 use bincode::{Decode, Encode};
 
 /// Identifies a sensor. See also [`SensorIds`](crate::SensorIds).
@@ -66,3 +66,18 @@ impl From<&Version1DataFrame> for SensorId {
         Self::from(value)
     }
 }
+
+impl From<&Version2DataFrame> for SensorId {
+    fn from(value: &Version2DataFrame) -> Self {
+        Self(
+            value.sensor_tag,
+            value.value.sensor_type_id(),
+            value.value.value_type(),
+        )
+    }
+}
+
+impl MaxEncodedLen for SensorId {
+    const MAX_ENCODED_LEN: usize =
+        u16::MAX_ENCODED_LEN + u8::MAX_ENCODED_LEN + ValueType::MAX_ENCODED_LEN;
+}
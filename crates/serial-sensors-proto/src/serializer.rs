@@ -1,4 +1,9 @@
-use crate::versions::{Version1, Version1DataFrame};
+use crate::checksum::FLAGS_MASK;
+use crate::versions::{
+    AnyVersionFrame, BatchDataFrame, CompactDataFrame, FrameKind, Version1, Version1DataFrame,
+    Version2, Version2DataFrame, VersionMismatch,
+};
+use crate::Checksum;
 use crate::{DataFrame, ProtocolVersion, VersionedDataFrame};
 use bincode::config::{Configuration, Fixint, LittleEndian};
 use bincode::error::{DecodeError, EncodeError};
@@ -20,13 +25,44 @@ pub(crate) const SERIALIZATION_CONFIG: Configuration<LittleEndian, Fixint> =
 /// The function returns an error when serialization failed, or when either serialization
 /// or byte stuffing resulted in a buffer overrun.
 pub fn serialize<I, V, D>(frame: I, buffer: &mut [u8]) -> Result<Range<usize>, SerializationError>
+where
+    I: Into<VersionedDataFrame<V, D>>,
+    V: ProtocolVersion,
+    D: DataFrame + Encode,
+{
+    serialize_with_checksum(frame, Checksum::None, buffer)
+}
+
+/// Serializes data, appends the specified [`Checksum`] and applies byte stuffing.
+///
+/// The checksum is calculated over the serialized frame and is announced by a capability
+/// flag in the version byte; [`deserialize`] verifies it automatically.
+///
+/// ## Errors
+/// The function returns an error when serialization failed, or when either serialization
+/// or byte stuffing resulted in a buffer overrun.
+pub fn serialize_with_checksum<I, V, D>(
+    frame: I,
+    checksum: Checksum,
+    buffer: &mut [u8],
+) -> Result<Range<usize>, SerializationError>
 where
     I: Into<VersionedDataFrame<V, D>>,
     V: ProtocolVersion,
     D: DataFrame + Encode,
 {
     let frame = frame.into();
-    let num_serialized = bincode::encode_into_slice(frame, buffer, SERIALIZATION_CONFIG)?;
+    let num_encoded = bincode::encode_into_slice(frame, buffer, SERIALIZATION_CONFIG)?;
+
+    // Flag the checksum in the version byte and append the trailer.
+    let num_serialized = num_encoded + checksum.len();
+    if num_serialized > buffer.len() {
+        return Err(SerializationError::WouldOverflow(num_serialized));
+    }
+
+    buffer[0] |= checksum.flag();
+    let (data, trailer) = buffer[..num_serialized].split_at_mut(num_encoded);
+    checksum.write(data, trailer);
 
     // Split the buffer into the source part and the destination part.
     let (source, target) = buffer.split_at_mut(num_serialized);
@@ -43,7 +79,9 @@ where
 
 /// Deserializes data after applying byte un-stuffing.
 ///
-/// Returns the number of bytes read from the buffer
+/// Returns the number of bytes read from the buffer. The buffer is expected to hold
+/// exactly one frame; use a [`FrameDecoder`](crate::FrameDecoder) to decode a continuous
+/// byte stream.
 ///
 /// ## Errors
 /// Returns an error when byte un-stuffing failed, e.g. due to a buffer under-run or corrupted data,
@@ -51,13 +89,127 @@ where
 pub fn deserialize(
     buffer: &mut [u8],
 ) -> Result<(usize, VersionedDataFrame<Version1, Version1DataFrame>), DeserializationError> {
-    // TODO: Ensure that sync recovery actually works.
     let read_length = corncobs::decode_in_place(buffer)?;
-    let data = &buffer[..read_length];
+    let data = strip_checksum(&mut buffer[..read_length])?;
+    Version1::try_from(data[0])?;
+    let (data, _) = bincode::decode_from_slice(data, SERIALIZATION_CONFIG)?;
+    Ok((read_length, data))
+}
+
+/// Deserializes a [`BatchDataFrame`] after applying byte un-stuffing.
+///
+/// Returns the number of bytes read from the buffer. Batches whose samples exceed `N`
+/// bytes are rejected.
+///
+/// ## Errors
+/// Returns an error when byte un-stuffing failed, e.g. due to a buffer under-run or corrupted data,
+/// when the frame is not a batch, or when deserialization failed due to unknown wire data.
+pub fn deserialize_batch<const N: usize>(
+    buffer: &mut [u8],
+) -> Result<(usize, VersionedDataFrame<Version2, BatchDataFrame<N>>), DeserializationError> {
+    let read_length = corncobs::decode_in_place(buffer)?;
+    let data = strip_checksum(&mut buffer[..read_length])?;
+    Version2::try_from(data[0])?;
+    let kind = frame_kind(data)?;
+    if kind != FrameKind::Batch {
+        return Err(DeserializationError::UnexpectedFrameKind(kind));
+    }
+    let (data, _) = bincode::decode_from_slice(data, SERIALIZATION_CONFIG)?;
+    Ok((read_length, data))
+}
+
+/// Deserializes a [`CompactDataFrame`] after applying byte un-stuffing.
+///
+/// Returns the number of bytes read from the buffer. The frame needs to be passed
+/// to a [`CompactDecoder`](crate::versions::CompactDecoder) to restore its header.
+///
+/// ## Errors
+/// Returns an error when byte un-stuffing failed, e.g. due to a buffer under-run or corrupted data,
+/// when the frame is not a compact frame, or when deserialization failed due to unknown wire data.
+pub fn deserialize_compact(
+    buffer: &mut [u8],
+) -> Result<(usize, VersionedDataFrame<Version2, CompactDataFrame>), DeserializationError> {
+    let read_length = corncobs::decode_in_place(buffer)?;
+    let data = strip_checksum(&mut buffer[..read_length])?;
+    Version2::try_from(data[0])?;
+    let kind = frame_kind(data)?;
+    if kind != FrameKind::Compact {
+        return Err(DeserializationError::UnexpectedFrameKind(kind));
+    }
     let (data, _) = bincode::decode_from_slice(data, SERIALIZATION_CONFIG)?;
     Ok((read_length, data))
 }
 
+/// Deserializes data of any supported protocol version after applying byte un-stuffing.
+///
+/// The version byte of the frame and, for version 2 frames, its [`FrameKind`] determine the type
+/// of the returned [`AnyVersionFrame`]. Returns the number of bytes read from the buffer.
+/// Compact frames need to be passed to a [`CompactDecoder`](crate::versions::CompactDecoder)
+/// to restore their header.
+///
+/// ## Errors
+/// Returns an error when byte un-stuffing failed, e.g. due to a buffer under-run or corrupted data,
+/// when the protocol version or frame kind is unknown, or when deserialization failed due to
+/// unknown wire data.
+pub fn deserialize_any(
+    buffer: &mut [u8],
+) -> Result<(usize, AnyVersionFrame), DeserializationError> {
+    let read_length = corncobs::decode_in_place(buffer)?;
+    let data = strip_checksum(&mut buffer[..read_length])?;
+    let frame = match usize::from(data[0]) {
+        Version1::VERSION => {
+            let (frame, _): (VersionedDataFrame<Version1, Version1DataFrame>, _) =
+                bincode::decode_from_slice(data, SERIALIZATION_CONFIG)?;
+            frame.into()
+        }
+        Version2::VERSION => match frame_kind(data)? {
+            FrameKind::Single => {
+                let (frame, _): (VersionedDataFrame<Version2, Version2DataFrame>, _) =
+                    bincode::decode_from_slice(data, SERIALIZATION_CONFIG)?;
+                frame.into()
+            }
+            FrameKind::Batch => {
+                let (frame, _) = bincode::decode_from_slice(data, SERIALIZATION_CONFIG)?;
+                AnyVersionFrame::Batch(frame)
+            }
+            FrameKind::Compact => {
+                let (frame, _) = bincode::decode_from_slice(data, SERIALIZATION_CONFIG)?;
+                AnyVersionFrame::Compact(frame)
+            }
+        },
+        _ => return Err(DeserializationError::UnknownVersion(data[0])),
+    };
+    Ok((read_length, frame))
+}
+
+/// Returns the kind of a version 2 frame, which follows the version byte.
+fn frame_kind(data: &[u8]) -> Result<FrameKind, DeserializationError> {
+    let Some(&kind) = data.get(1) else {
+        return Err(DeserializationError::Truncated);
+    };
+    FrameKind::try_from(kind).map_err(|()| DeserializationError::UnknownFrameKind(kind))
+}
+
+/// Verifies and removes the checksum trailer, if the version byte announces one.
+fn strip_checksum(data: &mut [u8]) -> Result<&[u8], DeserializationError> {
+    let Some(&version) = data.first() else {
+        return Err(DeserializationError::Truncated);
+    };
+
+    let checksum = Checksum::from_flags(version).ok_or(DeserializationError::Corrupt)?;
+    let Some(length) = data.len().checked_sub(checksum.len()) else {
+        return Err(DeserializationError::Truncated);
+    };
+
+    let (payload, trailer) = data.split_at_mut(length);
+    if !checksum.verify(payload, trailer) {
+        return Err(DeserializationError::ChecksumMismatch);
+    }
+
+    payload[0] &= !FLAGS_MASK;
+    Ok(payload)
+}
+
 /// A serialization error.
 #[derive(Debug)]
 pub enum SerializationError {
@@ -66,6 +218,8 @@ pub enum SerializationError {
     /// Byte stuffing would overflow the provided buffer.
     /// At least the contained amount of bytes is required.
     WouldOverflow(usize),
+    /// Writing the frame to a sink failed.
+    Io(IoError),
 }
 
 /// A deserialization error.
@@ -77,6 +231,75 @@ pub enum DeserializationError {
     Corrupt,
     /// Decoding failed.
     BincodeError(DecodeError),
+    /// The frame exceeded the receive buffer and was dropped.
+    FrameTooLong,
+    /// The frame checksum did not match its contents.
+    ChecksumMismatch,
+    /// The frame uses an unknown protocol version.
+    UnknownVersion(u8),
+    /// The frame uses a different protocol version than expected.
+    VersionMismatch(VersionMismatch),
+    /// The frame uses an unknown frame kind.
+    UnknownFrameKind(u8),
+    /// The frame is of a different kind than expected.
+    UnexpectedFrameKind(FrameKind),
+    /// A delta-coded frame was received without a preceding keyframe, or a frame was lost.
+    MissingKeyframe,
+    /// Reading from a source failed.
+    Io(IoError),
+}
+
+/// An error reported by the sink or source of a transport.
+#[derive(Debug)]
+pub enum IoError {
+    /// An [`embedded_io`] transport failed with the contained error kind.
+    #[cfg(feature = "embedded-io")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "embedded-io")))]
+    EmbeddedIo(embedded_io::ErrorKind),
+    /// A [`std::io`] reader or writer failed.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    Std(std::io::Error),
+}
+
+#[cfg(feature = "embedded-io")]
+impl From<embedded_io::ErrorKind> for IoError {
+    fn from(value: embedded_io::ErrorKind) -> Self {
+        IoError::EmbeddedIo(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for IoError {
+    fn from(value: std::io::Error) -> Self {
+        IoError::Std(value)
+    }
+}
+
+impl core::fmt::Display for IoError {
+    #[cfg_attr(
+        not(any(feature = "embedded-io", feature = "std")),
+        allow(unused_variables)
+    )]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            #[cfg(feature = "embedded-io")]
+            IoError::EmbeddedIo(kind) => write!(f, "{kind:?}"),
+            #[cfg(feature = "std")]
+            IoError::Std(ref err) => core::fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            #[cfg(feature = "embedded-io")]
+            IoError::EmbeddedIo(_) => None,
+            IoError::Std(ref err) => Some(err),
+        }
+    }
 }
 
 impl From<EncodeError> for SerializationError {
@@ -94,6 +317,12 @@ impl From<CobsError> for DeserializationError {
     }
 }
 
+impl From<VersionMismatch> for DeserializationError {
+    fn from(value: VersionMismatch) -> Self {
+        DeserializationError::VersionMismatch(value)
+    }
+}
+
 impl From<DecodeError> for DeserializationError {
     fn from(value: DecodeError) -> Self {
         DeserializationError::BincodeError(value)
@@ -106,6 +335,18 @@ impl core::fmt::Display for DeserializationError {
             DeserializationError::Truncated => f.write_str("input truncated"),
             DeserializationError::Corrupt => f.write_str("input corrupt"),
             DeserializationError::BincodeError(err) => core::fmt::Display::fmt(&err, f),
+            DeserializationError::FrameTooLong => f.write_str("frame too long"),
+            DeserializationError::ChecksumMismatch => f.write_str("checksum mismatch"),
+            DeserializationError::UnknownVersion(version) => {
+                write!(f, "unknown protocol version {version}")
+            }
+            DeserializationError::VersionMismatch(err) => core::fmt::Display::fmt(&err, f),
+            DeserializationError::UnknownFrameKind(kind) => write!(f, "unknown frame kind {kind}"),
+            DeserializationError::UnexpectedFrameKind(kind) => {
+                write!(f, "unexpected frame kind {kind:?}")
+            }
+            DeserializationError::MissingKeyframe => f.write_str("missing keyframe"),
+            DeserializationError::Io(err) => write!(f, "read failed: {err}"),
         }
     }
 }
@@ -114,10 +355,11 @@ impl core::fmt::Display for DeserializationError {
 mod tests {
     use super::*;
     use crate::types::AccelerometerI16;
-    use crate::versions::Version1DataFrame;
-    use crate::Vector3Data;
+    use crate::versions::{CompactDecoder, CompactEncoder, TimeBase};
+    use crate::{SensorData, ValueType, Vector3Data};
 
     #[test]
+    #[allow(clippy::unwrap_used)]
     fn test_serialize() {
         let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
         let frame = Version1DataFrame::new(0, 0, 0, u32::MAX, 12, 0, value);
@@ -140,4 +382,223 @@ mod tests {
         assert_eq!(data.y, -2);
         assert_eq!(data.z, 3);
     }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_serialize_with_checksum() {
+        let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+        let frame = Version1DataFrame::new(0, 0, 0, u32::MAX, 12, 0, value);
+
+        for (checksum, length) in [(Checksum::Crc16, 31), (Checksum::Crc32, 33)] {
+            let mut buffer = [0_u8; 80];
+            let range = serialize_with_checksum(frame.clone(), checksum, &mut buffer).unwrap();
+            assert_eq!(range.len(), length);
+
+            let (_read, data) = deserialize(&mut buffer[range]).unwrap();
+            assert_eq!(data.version, Version1);
+            assert_eq!(data.data, frame);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_max_wire_len() {
+        use crate::types::DistanceZonesU16;
+        use crate::ArrayData;
+
+        assert_eq!(Version1DataFrame::MAX_WIRE_LEN, 155);
+
+        // The largest reading without any zero bytes requires the most byte stuffing.
+        let value = DistanceZonesU16::new(ArrayData::new([0xFFFF; 64]));
+        let frame = Version1DataFrame::new(u32::MAX, 999, 999, u32::MAX, u32::MAX, 1, value);
+
+        let mut buffer = [0_u8; Version1DataFrame::MAX_SERIALIZE_BUFFER_LEN];
+        let range = serialize_with_checksum(frame.clone(), Checksum::Crc32, &mut buffer).unwrap();
+        assert!(range.len() <= Version1DataFrame::MAX_WIRE_LEN);
+
+        #[cfg(feature = "embedded-io")]
+        {
+            let mut buffer = [0_u8; Version1DataFrame::MAX_WIRE_LEN];
+            let mut writer = &mut buffer[..];
+            let written = crate::serialize_to_writer(frame, Checksum::Crc32, &mut writer).unwrap();
+            assert_eq!(written, range.len());
+        }
+
+        let mut buffer = [0_u8; Version2DataFrame::MAX_SERIALIZE_BUFFER_LEN];
+        let frame = Version2DataFrame::new(u64::MAX, TimeBase::Gps, 1, 2, 3, value);
+        let range = serialize_with_checksum(frame, Checksum::Crc32, &mut buffer).unwrap();
+        assert!(range.len() <= Version2DataFrame::MAX_WIRE_LEN);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_checksum_mismatch() {
+        let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+        let frame = Version1DataFrame::new(0, 0, 0, u32::MAX, 12, 0, value);
+
+        let mut buffer = [0_u8; 80];
+        let range = serialize_with_checksum(frame, Checksum::Crc16, &mut buffer).unwrap();
+
+        // Flip a bit in the sensor data; COBS framing remains intact.
+        let mut encoded = [0_u8; 31];
+        encoded.copy_from_slice(&buffer[range]);
+        encoded[24] ^= 0x01;
+
+        assert!(matches!(
+            deserialize(&mut encoded),
+            Err(DeserializationError::ChecksumMismatch)
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_deserialize_any() {
+        let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+
+        let mut buffer = [0_u8; 80];
+        let frame = Version1DataFrame::new(0, 0, 0, 1, 12, 0, value);
+        let range = serialize(frame.clone(), &mut buffer).unwrap();
+        let (_read, data) = deserialize_any(&mut buffer[range]).unwrap();
+        assert_eq!(data, AnyVersionFrame::Version1(frame.into_versioned()));
+
+        let mut buffer = [0_u8; 80];
+        let frame = Version2DataFrame::new(1_000, TimeBase::Unix, 2, 13, 0, value);
+        let range = serialize_with_checksum(frame.clone(), Checksum::Crc32, &mut buffer).unwrap();
+        let (_read, data) = deserialize_any(&mut buffer[range]).unwrap();
+        assert_eq!(data.version(), 2);
+        assert_eq!(data.sensor_sequence(), Some(13));
+        assert_eq!(data, AnyVersionFrame::Version2(frame.into_versioned()));
+    }
+
+    #[test]
+    fn test_deserialize_any_unknown_version() {
+        // COBS-encoded version byte 0x2A followed by a single zero byte.
+        let mut buffer = [0x02, 0x2A, 0x01, 0x00];
+        assert!(matches!(
+            deserialize_any(&mut buffer),
+            Err(DeserializationError::UnknownVersion(0x2A))
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_deserialize_version_mismatch() {
+        let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+        let frame = Version2DataFrame::new(1_000, TimeBase::Unix, 2, 13, 0, value);
+
+        let mut buffer = [0_u8; 80];
+        let range = serialize(frame, &mut buffer).unwrap();
+        assert!(matches!(
+            deserialize(&mut buffer[range]),
+            Err(DeserializationError::VersionMismatch(VersionMismatch {
+                expected: 1,
+                received: 2
+            }))
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_serialize_vendor_frame() {
+        let value = SensorData::vendor(0xC0, ValueType::UInt16, &[0x34, 0x12]).unwrap();
+        let frame = Version1DataFrame::new(0, 0, 0, 1, 2, 3, value);
+
+        let mut buffer = [0_u8; 64];
+        let range = serialize(frame.clone(), &mut buffer).unwrap();
+
+        let (_read, data) = deserialize(&mut buffer[range]).unwrap();
+        assert_eq!(data.data, frame);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_serialize_batch() {
+        let mut batch = BatchDataFrame::<64>::new(0, TimeBase::BootRelative, 1_000_000, 1, 0, 0);
+        for x in 0..10 {
+            batch
+                .push(AccelerometerI16::new(Vector3Data { x, y: -2, z: 3 }))
+                .unwrap();
+        }
+
+        let mut buffer = [0_u8; 200];
+        let range = serialize_with_checksum(batch.clone(), Checksum::Crc16, &mut buffer).unwrap();
+
+        // Ten individual frames would take 10 × 29 bytes.
+        assert_eq!(range.len(), 29 + 10 * 6 + 2 + 2);
+
+        let (_, frame) = deserialize_batch::<64>(&mut buffer[range.clone()]).unwrap();
+        assert_eq!(frame.data, batch);
+        assert_eq!(frame.data.frames().count(), 10);
+
+        let range = serialize(batch.clone(), &mut buffer).unwrap();
+        assert!(matches!(
+            deserialize(&mut buffer[range]),
+            Err(DeserializationError::VersionMismatch(_))
+        ));
+
+        let range = serialize(batch.clone(), &mut buffer).unwrap();
+        let (_, frame) = deserialize_any(&mut buffer[range]).unwrap();
+        let AnyVersionFrame::Batch(frame) = frame else {
+            panic!("Expected a batch");
+        };
+        assert_eq!(frame.data.len(), 10);
+        for (received, sent) in frame.data.frames().zip(batch.frames()) {
+            assert_eq!(received.unwrap(), sent.unwrap());
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_deserialize_frame_kind() {
+        let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+        let frame = Version2DataFrame::new(1_000, TimeBase::Unix, 2, 13, 0, value);
+
+        let mut buffer = [0_u8; 80];
+        let range = serialize(frame, &mut buffer).unwrap();
+        assert!(matches!(
+            deserialize_batch::<64>(&mut buffer[range]),
+            Err(DeserializationError::UnexpectedFrameKind(FrameKind::Single))
+        ));
+
+        // COBS-encoded version 2 frame of kind 0x2A.
+        let mut buffer = [0x03, 0x02, 0x2A, 0x00];
+        assert!(matches!(
+            deserialize_any(&mut buffer),
+            Err(DeserializationError::UnknownFrameKind(0x2A))
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_serialize_compact() {
+        let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+        let mut encoder: CompactEncoder = CompactEncoder::new(0);
+        let mut decoder: CompactDecoder = CompactDecoder::new();
+
+        for (sequence, length) in [(1, 20), (2, 19)] {
+            let frame = Version2DataFrame::new(
+                u64::from(sequence) * 1_000_000,
+                TimeBase::BootRelative,
+                sequence,
+                sequence,
+                0,
+                value,
+            );
+
+            // Compared to 29 bytes for the version 1 frame.
+            let mut buffer = [0_u8; 64];
+            let compact = encoder.encode(frame.clone());
+            let range = serialize(compact.clone(), &mut buffer).unwrap();
+            assert_eq!(range.len(), length);
+            assert_eq!(
+                deserialize_any(&mut buffer[range]).unwrap().1,
+                AnyVersionFrame::Compact(compact.clone().into_versioned())
+            );
+
+            let range = serialize(compact, &mut buffer).unwrap();
+
+            let (_, compact) = deserialize_compact(&mut buffer[range]).unwrap();
+            assert_eq!(decoder.decode(compact.data).unwrap(), frame);
+        }
+    }
 }
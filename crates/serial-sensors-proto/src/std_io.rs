@@ -0,0 +1,284 @@
+use crate::decoder::ChunkDecoder;
+use crate::versions::{AnyVersionFrame, Version1, Version1DataFrame};
+use crate::{
+    deserialize, deserialize_any, serialize_to_std_writer, Checksum, DataFrame,
+    DeserializationError, ProtocolVersion, SerializationError, VersionedDataFrame,
+};
+use bincode::Encode;
+use std::io::{ErrorKind, Read, Write};
+
+/// The number of bytes requested from the reader at once.
+const READ_CHUNK_LEN: usize = 256;
+
+/// Decodes frames from a [`std::io::Read`] source, such as a file or a serial port.
+///
+/// The iterator yields every decoded frame, as well as an error for every frame that had to be
+/// dropped; decoding then resumes at the next delimiter. Read errors of the source are reported
+/// as [`DeserializationError::Io`]. The iterator ends when the source reaches end of file; an
+/// incomplete trailing frame is reported as [`DeserializationError::Truncated`].
+///
+/// Frames are collected in a [`FrameDecoder`](crate::FrameDecoder) of `N` bytes; the default
+/// is large enough for any version 1 frame. Readers created with [`FrameReader::new_any`] yield
+/// an [`AnyVersionFrame`] for frames of any version and kind, and need a buffer of
+/// [`AnyVersionFrame::MAX_WIRE_LEN`] bytes to accept every frame.
+///
+/// ```
+/// # use serial_sensors_proto::{FrameReader, FrameWriter, Vector3Data};
+/// # use serial_sensors_proto::types::AccelerometerI16;
+/// # use serial_sensors_proto::versions::Version1DataFrame;
+/// let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+/// let mut writer = FrameWriter::new(Vec::new());
+/// for sequence in 0..3 {
+///     writer.write(Version1DataFrame::new(0, 0, 0, 1, sequence, 0, value.clone())).unwrap();
+/// }
+///
+/// let capture = writer.into_inner();
+/// let reader: FrameReader<_> = FrameReader::new(capture.as_slice());
+/// let sequences: Vec<_> = reader.map(|frame| frame.unwrap().data.sensor_sequence).collect();
+/// assert_eq!(sequences, [0, 1, 2]);
+/// ```
+#[derive(Debug)]
+pub struct FrameReader<
+    R,
+    const N: usize = { Version1DataFrame::MAX_WIRE_LEN },
+    T = VersionedDataFrame<Version1, Version1DataFrame>,
+> {
+    reader: R,
+    decoder: ChunkDecoder<N, READ_CHUNK_LEN, T>,
+}
+
+impl<R, const N: usize> FrameReader<R, N>
+where
+    R: Read,
+{
+    /// Creates a new reader decoding version 1 frames from the specified source.
+    pub const fn new(reader: R) -> Self {
+        Self {
+            reader,
+            decoder: ChunkDecoder::new(deserialize),
+        }
+    }
+}
+
+impl<R, const N: usize> FrameReader<R, N, AnyVersionFrame>
+where
+    R: Read,
+{
+    /// Creates a new reader decoding frames of any supported protocol version and kind
+    /// from the specified source.
+    ///
+    /// ```
+    /// # use serial_sensors_proto::{FrameReader, FrameWriter, Vector3Data};
+    /// # use serial_sensors_proto::types::AccelerometerI16;
+    /// # use serial_sensors_proto::versions::{AnyVersionFrame, TimeBase, Version1DataFrame, Version2DataFrame};
+    /// let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+    /// let mut writer = FrameWriter::new(Vec::new());
+    /// writer.write(Version1DataFrame::new(0, 0, 0, 1, 1, 0, value.clone())).unwrap();
+    /// writer.write(Version2DataFrame::new(0, TimeBase::Unix, 2, 2, 0, value)).unwrap();
+    ///
+    /// let capture = writer.into_inner();
+    /// let reader: FrameReader<_, { AnyVersionFrame::MAX_WIRE_LEN }, _> =
+    ///     FrameReader::new_any(capture.as_slice());
+    /// let versions: Vec<_> = reader.map(|frame| frame.unwrap().version()).collect();
+    /// assert_eq!(versions, [1, 2]);
+    /// ```
+    pub const fn new_any(reader: R) -> Self {
+        Self {
+            reader,
+            decoder: ChunkDecoder::new(deserialize_any),
+        }
+    }
+}
+
+impl<R, const N: usize, T> FrameReader<R, N, T> {
+    /// Returns a reference to the source.
+    pub fn reader(&self) -> &R {
+        &self.reader
+    }
+
+    /// Consumes the reader and returns the source.
+    ///
+    /// Bytes that were already read but not yet decoded are lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R, const N: usize, T> Iterator for FrameReader<R, N, T>
+where
+    R: Read,
+{
+    type Item = Result<T, DeserializationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(result) = self.decoder.decode() {
+                return Some(result);
+            }
+
+            if self.decoder.is_eof() {
+                return None;
+            }
+
+            match self.reader.read(self.decoder.chunk()) {
+                Ok(len) => {
+                    if let Some(err) = self.decoder.filled(len) {
+                        return Some(Err(err));
+                    }
+                }
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Some(Err(DeserializationError::Io(err.into()))),
+            }
+        }
+    }
+}
+
+/// Writes frames to a [`std::io::Write`] sink, such as a file or a serial port.
+///
+/// Every frame is serialized, byte-stuffed and terminated with the delimiter, so that the
+/// output can be read back with a [`FrameReader`].
+#[derive(Debug)]
+pub struct FrameWriter<W> {
+    writer: W,
+    checksum: Checksum,
+}
+
+impl<W> FrameWriter<W>
+where
+    W: Write,
+{
+    /// Creates a new writer that does not append a checksum.
+    pub const fn new(writer: W) -> Self {
+        Self::with_checksum(writer, Checksum::None)
+    }
+
+    /// Creates a new writer that appends the specified [`Checksum`] to every frame.
+    pub const fn with_checksum(writer: W, checksum: Checksum) -> Self {
+        Self { writer, checksum }
+    }
+
+    /// Serializes and writes a frame.
+    ///
+    /// Returns the number of bytes written, including the delimiter. The sink is not flushed;
+    /// use [`FrameWriter::flush`] for that.
+    ///
+    /// ## Errors
+    /// Returns an error when serialization failed or the sink reported an error.
+    /// In the latter case, a partial frame may have been written; readers discard it at the
+    /// next delimiter.
+    pub fn write<I, V, D>(&mut self, frame: I) -> Result<usize, SerializationError>
+    where
+        I: Into<VersionedDataFrame<V, D>>,
+        V: ProtocolVersion,
+        D: DataFrame + Encode,
+    {
+        serialize_to_std_writer(frame, self.checksum, &mut self.writer)
+    }
+
+    /// Flushes the sink.
+    ///
+    /// ## Errors
+    /// Returns an error when the sink reported an error.
+    pub fn flush(&mut self) -> Result<(), SerializationError> {
+        self.writer
+            .flush()
+            .map_err(|err| SerializationError::Io(err.into()))
+    }
+
+    /// Returns a reference to the sink.
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the writer and returns the sink.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{frame, frame_v2, reading, Trickle};
+    use crate::versions::{BatchDataFrame, CompactEncoder, TimeBase};
+    use crate::IoError;
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_roundtrip() {
+        let mut writer = FrameWriter::with_checksum(Vec::new(), Checksum::Crc32);
+        for sequence in 0..100 {
+            writer.write(frame(sequence)).unwrap();
+        }
+        writer.flush().unwrap();
+
+        let capture = writer.into_inner();
+        let reader: FrameReader<_> = FrameReader::new(capture.as_slice());
+        let frames: Vec<_> = reader.map(Result::unwrap).collect();
+        assert_eq!(frames.len(), 100);
+        for (sequence, received) in (0..).zip(frames) {
+            assert_eq!(received.data, frame(sequence));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_resync() {
+        let mut writer = FrameWriter::new(vec![0x13, 0x37]);
+        writer.write(frame(1)).unwrap();
+        writer.write(frame(2)).unwrap();
+        let mut capture = writer.into_inner();
+        capture.extend_from_slice(&[0x05, 0x01]);
+
+        let mut reader: FrameReader<_> = FrameReader::new(Trickle::with_data(&capture, 9));
+
+        assert!(matches!(
+            reader.next(),
+            Some(Err(DeserializationError::Io(IoError::Std(err))))
+                if err.kind() == ErrorKind::ConnectionReset
+        ));
+        assert!(reader.next().unwrap().is_err());
+        assert_eq!(reader.next().unwrap().unwrap().data, frame(2));
+        assert!(matches!(
+            reader.next(),
+            Some(Err(DeserializationError::Truncated))
+        ));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_mixed_stream() {
+        let mut batch = BatchDataFrame::<64>::new(0, TimeBase::Unix, 1_000, 3, 3, 0);
+        batch.push(reading()).unwrap();
+        batch.push(reading()).unwrap();
+
+        let mut writer = FrameWriter::with_checksum(Vec::new(), Checksum::Crc16);
+        writer.write(frame(1)).unwrap();
+        writer.write(frame_v2(2)).unwrap();
+        writer.write(batch).unwrap();
+        let mut encoder: CompactEncoder = CompactEncoder::new(0);
+        writer.write(encoder.encode(frame_v2(4))).unwrap();
+
+        let capture = writer.into_inner();
+        let mut reader: FrameReader<_, { AnyVersionFrame::MAX_WIRE_LEN }, _> =
+            FrameReader::new_any(capture.as_slice());
+        assert_eq!(
+            reader.next().unwrap().unwrap(),
+            AnyVersionFrame::Version1(frame(1).into())
+        );
+        assert_eq!(
+            reader.next().unwrap().unwrap(),
+            AnyVersionFrame::Version2(frame_v2(2).into())
+        );
+        assert!(matches!(
+            reader.next(),
+            Some(Ok(AnyVersionFrame::Batch(frame))) if frame.data.len() == 2
+        ));
+        assert!(matches!(
+            reader.next(),
+            Some(Ok(AnyVersionFrame::Compact(frame))) if frame.data.is_keyframe()
+        ));
+        assert!(reader.next().is_none());
+    }
+}
@@ -0,0 +1,142 @@
+use crate::decoder::ChunkDecoder;
+use crate::versions::{AnyVersionFrame, Version1, Version1DataFrame};
+use crate::{deserialize, deserialize_any, DeserializationError, VersionedDataFrame};
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::Stream;
+use tokio::io::{AsyncRead, ReadBuf};
+
+/// The number of bytes requested from the reader at once.
+const READ_CHUNK_LEN: usize = 64;
+
+/// Decodes frames from a [`tokio::io::AsyncRead`] source, such as a serial port or a socket.
+///
+/// This is the asynchronous counterpart of the [`FrameReader`](crate::FrameReader); frames,
+/// dropped frames, read errors and the end of the source are reported the same way, and
+/// streams created with [`FrameStream::new_any`] accept frames of any version and kind.
+#[derive(Debug)]
+pub struct FrameStream<
+    R,
+    const N: usize = { Version1DataFrame::MAX_WIRE_LEN },
+    T = VersionedDataFrame<Version1, Version1DataFrame>,
+> {
+    reader: R,
+    decoder: ChunkDecoder<N, READ_CHUNK_LEN, T>,
+}
+
+impl<R, const N: usize> FrameStream<R, N>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Creates a new stream decoding version 1 frames from the specified source.
+    pub const fn new(reader: R) -> Self {
+        Self {
+            reader,
+            decoder: ChunkDecoder::new(deserialize),
+        }
+    }
+}
+
+impl<R, const N: usize> FrameStream<R, N, AnyVersionFrame>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Creates a new stream decoding frames of any supported protocol version and kind
+    /// from the specified source.
+    ///
+    /// Use a buffer of [`AnyVersionFrame::MAX_WIRE_LEN`] bytes to accept every frame.
+    pub const fn new_any(reader: R) -> Self {
+        Self {
+            reader,
+            decoder: ChunkDecoder::new(deserialize_any),
+        }
+    }
+}
+
+impl<R, const N: usize, T> FrameStream<R, N, T> {
+    /// Returns a reference to the source.
+    pub fn reader(&self) -> &R {
+        &self.reader
+    }
+
+    /// Consumes the stream and returns the source.
+    ///
+    /// Bytes that were already read but not yet decoded are lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R, const N: usize, T> Stream for FrameStream<R, N, T>
+where
+    R: AsyncRead + Unpin,
+{
+    type Item = Result<T, DeserializationError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(result) = this.decoder.decode() {
+                return Poll::Ready(Some(result));
+            }
+
+            if this.decoder.is_eof() {
+                return Poll::Ready(None);
+            }
+
+            let mut buf = ReadBuf::new(this.decoder.chunk());
+            if let Err(err) = ready!(Pin::new(&mut this.reader).poll_read(cx, &mut buf)) {
+                return Poll::Ready(Some(Err(DeserializationError::Io(err.into()))));
+            }
+
+            let len = buf.filled().len();
+            if let Some(err) = this.decoder.filled(len) {
+                return Poll::Ready(Some(Err(err)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::frame;
+    use crate::{serialize_to_std_writer, Checksum};
+    use core::future::poll_fn;
+    use tokio::io::AsyncWriteExt;
+
+    async fn next<R: AsyncRead + Unpin>(
+        stream: &mut FrameStream<R>,
+    ) -> Option<<FrameStream<R> as Stream>::Item> {
+        poll_fn(|cx| Pin::new(&mut *stream).poll_next(cx)).await
+    }
+
+    #[tokio::test]
+    #[allow(clippy::unwrap_used)]
+    async fn test_frame_stream() {
+        let mut data = Vec::new();
+        serialize_to_std_writer(frame(1), Checksum::Crc16, &mut data).unwrap();
+        data.extend_from_slice(&[0x13, 0x37, 0x00]);
+        serialize_to_std_writer(frame(2), Checksum::None, &mut data).unwrap();
+        data.extend_from_slice(&[0x05, 0x01]);
+
+        let (mut client, server) = tokio::io::duplex(8);
+        let writer = tokio::spawn(async move {
+            for chunk in data.chunks(5) {
+                client.write_all(chunk).await.unwrap();
+            }
+        });
+
+        let mut stream: FrameStream<_> = FrameStream::new(server);
+        assert_eq!(next(&mut stream).await.unwrap().unwrap().data, frame(1));
+        assert!(next(&mut stream).await.unwrap().is_err());
+        assert_eq!(next(&mut stream).await.unwrap().unwrap().data, frame(2));
+
+        writer.await.unwrap();
+        assert!(matches!(
+            next(&mut stream).await,
+            Some(Err(DeserializationError::Truncated))
+        ));
+        assert!(next(&mut stream).await.is_none());
+    }
+}
@@ -21,6 +21,183 @@ use serial_sensors_proto_derive::SensorDataType;
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct SystemClockFrequency(crate::ScalarData<u32>);
 
+/// Event counter, 1×`u64`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EventCounterU64(crate::ScalarData<u64>);
+
+/// Event counter, 1×`u128`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EventCounterU128(crate::ScalarData<u128>);
+
+/// Timestamp in nanoseconds, 1×`u64`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TimestampU64(crate::ScalarData<u64>);
+
+/// Timestamp in nanoseconds, 1×`i128`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TimestampI128(crate::ScalarData<i128>);
+
+/// Bus voltage, 1×`u16`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BusVoltageU16(crate::ScalarData<u16>);
+
+/// Bus voltage in volts (V), 1×`f32`
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BusVoltageF32(crate::ScalarData<f32>);
+
+/// Shunt current, 1×`i16`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ShuntCurrentI16(crate::ScalarData<i16>);
+
+/// Shunt current in amperes (A), 1×`f32`
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ShuntCurrentF32(crate::ScalarData<f32>);
+
+/// Power, 1×`u16`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PowerU16(crate::ScalarData<u16>);
+
+/// Power in watts (W), 1×`f32`
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PowerF32(crate::ScalarData<f32>);
+
+/// Accumulated energy, 1×`i64`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EnergyI64(crate::ScalarData<i64>);
+
+/// Battery state of charge, charging flag and cell voltage.
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BatteryStateInfo(crate::BatteryState);
+
 /// Acceleration / gravity data, 3×`i16`
 #[derive(
     SensorDataType,
@@ -39,6 +216,47 @@ pub struct SystemClockFrequency(crate::ScalarData<u32>);
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct AccelerometerI16(crate::Vector3Data<i16>);
 
+/// Acceleration / gravity data, 3×`i32`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AccelerometerI32(crate::Vector3Data<i32>);
+
+/// Acceleration / gravity data in m/s², 3×`f32`
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AccelerometerF32(crate::Vector3Data<f32>);
+
+/// Acceleration / gravity data in m/s², 3×Q16.16
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AccelerometerQ16_16(crate::Vector3Data<crate::Q16_16>);
+
 /// Magnetic field strength data, 3×`i16`.
 #[derive(
     SensorDataType,
@@ -57,6 +275,29 @@ pub struct AccelerometerI16(crate::Vector3Data<i16>);
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct MagnetometerI16(crate::Vector3Data<i16>);
 
+/// Magnetic field strength data, 3×`i32`.
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MagnetometerI32(crate::Vector3Data<i32>);
+
+/// Magnetic field strength data in Gauss, 3×`f32`.
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MagnetometerF32(crate::Vector3Data<f32>);
+
 /// Temperature data, 1×`i16`
 #[derive(
     SensorDataType,
@@ -75,6 +316,24 @@ pub struct MagnetometerI16(crate::Vector3Data<i16>);
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct TemperatureI16(crate::ScalarData<i16>);
 
+/// Temperature data in °C, 1×Q8.8
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TemperatureQ8_8(crate::ScalarData<crate::Q8_8>);
+
 /// Angular acceleration data, 3×`i16`
 #[derive(
     SensorDataType,
@@ -93,16 +352,49 @@ pub struct TemperatureI16(crate::ScalarData<i16>);
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct GyroscopeI16(crate::Vector3Data<i16>);
 
+/// Angular acceleration data, 3×`i32`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GyroscopeI32(crate::Vector3Data<i32>);
+
+/// Angular acceleration data in rad/s, 3×`f32`
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GyroscopeF32(crate::Vector3Data<f32>);
+
 /// Euler angles, 3×`f32`
 #[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct EulerAnglesF32(crate::Vector3Data<f32>);
 
+/// Euler angles, 3×`f64`
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EulerAnglesF64(crate::Vector3Data<f64>);
+
 /// Orientation quaternion, 4×`f32`
 #[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct OrientationQuaternionF32(crate::Vector4Data<f32>);
 
+/// Orientation quaternion, 4×`f64`
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OrientationQuaternionF64(crate::Vector4Data<f64>);
+
 /// Heading angle towards magnetic north, 0..360.
 #[derive(
     SensorDataType,
@@ -121,6 +413,334 @@ pub struct OrientationQuaternionF32(crate::Vector4Data<f32>);
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct HeadingI16(crate::ScalarData<i16>);
 
+/// Barometric pressure in Pascal (Pa), 1×`u32`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PressureU32(crate::ScalarData<u32>);
+
+/// Barometric pressure in Pascal (Pa), 1×`i32`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PressureI32(crate::ScalarData<i32>);
+
+/// Barometric pressure in Pascal (Pa), 1×`f32`
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PressureF32(crate::ScalarData<f32>);
+
+/// Barometric altitude in meters, 1×`f32`
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AltitudeF32(crate::ScalarData<f32>);
+
+/// Altitude in meters, 1×Q32.32
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AltitudeQ32_32(crate::ScalarData<crate::Q32_32>);
+
+/// Relative humidity in 1/100 percent (0.01 %RH), 1×`u16`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RelativeHumidityU16(crate::ScalarData<u16>);
+
+/// Relative humidity in percent (%RH), 1×`f32`
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RelativeHumidityF32(crate::ScalarData<f32>);
+
+/// Volatile organic compounds (VOC) index, 1×`u16`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VocIndexU16(crate::ScalarData<u16>);
+
+/// Equivalent CO₂ (eCO₂) concentration in parts per million (ppm), 1×`u16`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Eco2U16(crate::ScalarData<u16>);
+
+/// CO₂ concentration in parts per million (ppm), 1×`u16`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Co2U16(crate::ScalarData<u16>);
+
+/// Particulate matter mass concentration in µg/m³ as PM1.0, PM2.5 and PM10, 3×`u16`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParticulateMatterU16(crate::Vector3Data<u16>);
+
+impl ParticulateMatterU16 {
+    /// Returns the PM1.0 concentration.
+    #[must_use]
+    pub const fn pm1_0(&self) -> u16 {
+        self.0.x
+    }
+
+    /// Returns the PM2.5 concentration.
+    #[must_use]
+    pub const fn pm2_5(&self) -> u16 {
+        self.0.y
+    }
+
+    /// Returns the PM10 concentration.
+    #[must_use]
+    pub const fn pm10(&self) -> u16 {
+        self.0.z
+    }
+}
+
+/// Illuminance, 1×`u32`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IlluminanceU32(crate::ScalarData<u32>);
+
+/// Illuminance in lux (lx), 1×`f32`
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IlluminanceF32(crate::ScalarData<f32>);
+
+/// Infrared light intensity, 1×`u16`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct InfraredLightU16(crate::ScalarData<u16>);
+
+/// Proximity count, 1×`u16`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ProximityU16(crate::ScalarData<u16>);
+
+/// Distance in millimeters (mm), 1×`u16`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DistanceU16(crate::ScalarData<u16>);
+
+/// Distances of 8×8 zones in millimeters (mm), 64×`u16`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DistanceZonesU16(crate::ArrayData<u16, 64>);
+
+/// A GNSS position as latitude and longitude in 10<sup>-7</sup> degrees and altitude in millimeters.
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GnssPositionI32(crate::GnssPosition);
+
+/// A GNSS velocity over ground in north, east and down direction in mm/s, 3×`i32`
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GnssVelocityI32(crate::Vector3Data<i32>);
+
+/// GNSS fix type and quality.
+#[derive(
+    SensorDataType,
+    Encode,
+    Decode,
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GnssFixInfo(crate::GnssFix);
+
 /// An identifier.
 #[derive(SensorDataType, Encode, Decode, Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -1,7 +1,17 @@
 //! Versioned types.
 
+mod any_version;
+mod batch;
+mod compact;
+mod frame_kind;
 mod protocol_version;
 mod version_1;
+mod version_2;
 
+pub use any_version::*;
+pub use batch::*;
+pub use compact::*;
+pub use frame_kind::*;
 pub use protocol_version::*;
 pub use version_1::*;
+pub use version_2::*;
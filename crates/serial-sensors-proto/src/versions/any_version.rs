@@ -0,0 +1,193 @@
+//! A data frame of any supported protocol version.
+
+use crate::versions::{
+    BatchDataFrame, CompactDataFrame, Version1, Version1DataFrame, Version2, Version2DataFrame,
+    MAX_BATCH_LEN,
+};
+use crate::{
+    max_wire_len, Checksum, DataFrame, MaxEncodedLen, ProtocolVersion, SensorData, SensorId,
+    VersionedDataFrame,
+};
+
+/// A data frame of any supported protocol version and kind, as returned by
+/// [`deserialize_any`](crate::deserialize_any).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AnyVersionFrame {
+    /// A protocol version 1 frame.
+    Version1(VersionedDataFrame<Version1, Version1DataFrame>),
+    /// A protocol version 2 frame carrying a single reading.
+    Version2(VersionedDataFrame<Version2, Version2DataFrame>),
+    /// A protocol version 2 frame carrying a batch of readings.
+    Batch(VersionedDataFrame<Version2, BatchDataFrame<MAX_BATCH_LEN>>),
+    /// A protocol version 2 frame carrying a single reading with a delta-coded header.
+    ///
+    /// Pass it to a [`CompactDecoder`](crate::versions::CompactDecoder) to restore its header.
+    Compact(VersionedDataFrame<Version2, CompactDataFrame>),
+}
+
+impl AnyVersionFrame {
+    /// The maximum number of bytes of a serialized frame of any version or kind on the wire,
+    /// accounting for a CRC-32 trailer, the byte stuffing overhead and the delimiter.
+    ///
+    /// This is the size required for receive buffers that accept mixed streams.
+    pub const MAX_WIRE_LEN: usize = max_wire_len(
+        max(
+            max(
+                VersionedDataFrame::<Version1, Version1DataFrame>::MAX_ENCODED_LEN,
+                VersionedDataFrame::<Version2, Version2DataFrame>::MAX_ENCODED_LEN,
+            ),
+            max(
+                VersionedDataFrame::<Version2, BatchDataFrame<MAX_BATCH_LEN>>::MAX_ENCODED_LEN,
+                VersionedDataFrame::<Version2, CompactDataFrame>::MAX_ENCODED_LEN,
+            ),
+        ),
+        Checksum::Crc32,
+    );
+
+    /// Returns the protocol version of the frame.
+    #[must_use]
+    pub fn version(&self) -> usize {
+        match self {
+            AnyVersionFrame::Version1(frame) => frame.version.version(),
+            AnyVersionFrame::Version2(frame) => frame.version.version(),
+            AnyVersionFrame::Batch(frame) => frame.version.version(),
+            AnyVersionFrame::Compact(frame) => frame.version.version(),
+        }
+    }
+
+    /// Returns the sensor reading.
+    ///
+    /// Returns `None` for batches, which carry multiple readings; use
+    /// [`BatchDataFrame::frames`] to access them.
+    #[must_use]
+    pub fn value(&self) -> Option<&SensorData> {
+        match self {
+            AnyVersionFrame::Version1(frame) => Some(&frame.data.value),
+            AnyVersionFrame::Version2(frame) => Some(&frame.data.value),
+            AnyVersionFrame::Batch(_) => None,
+            AnyVersionFrame::Compact(frame) => Some(frame.data.value()),
+        }
+    }
+
+    /// Returns the global sequence identifier.
+    ///
+    /// Returns `None` for compact frames, whose header needs to be restored first.
+    #[must_use]
+    pub fn global_sequence(&self) -> Option<u32> {
+        match self {
+            AnyVersionFrame::Version1(frame) => Some(frame.data.global_sequence),
+            AnyVersionFrame::Version2(frame) => Some(frame.data.global_sequence),
+            AnyVersionFrame::Batch(frame) => Some(frame.data.global_sequence),
+            AnyVersionFrame::Compact(_) => None,
+        }
+    }
+
+    /// Returns the sensor sequence identifier; for batches, this is the identifier of
+    /// the first sample.
+    ///
+    /// Returns `None` for compact frames, whose header needs to be restored first.
+    #[must_use]
+    pub fn sensor_sequence(&self) -> Option<u32> {
+        match self {
+            AnyVersionFrame::Version1(frame) => Some(frame.data.sensor_sequence),
+            AnyVersionFrame::Version2(frame) => Some(frame.data.sensor_sequence),
+            AnyVersionFrame::Batch(frame) => Some(frame.data.sensor_sequence),
+            AnyVersionFrame::Compact(_) => None,
+        }
+    }
+
+    /// Returns the sensor tag.
+    #[must_use]
+    pub fn sensor_tag(&self) -> u16 {
+        match self {
+            AnyVersionFrame::Version1(frame) => frame.data.sensor_tag,
+            AnyVersionFrame::Version2(frame) => frame.data.sensor_tag,
+            AnyVersionFrame::Batch(frame) => frame.data.sensor_tag,
+            AnyVersionFrame::Compact(frame) => frame.data.sensor_tag(),
+        }
+    }
+
+    /// Indicates whether the data resembles metadata.
+    #[must_use]
+    pub fn is_meta(&self) -> bool {
+        match self {
+            AnyVersionFrame::Version1(frame) => frame.data.is_meta(),
+            AnyVersionFrame::Version2(frame) => frame.data.is_meta(),
+            AnyVersionFrame::Batch(frame) => frame.data.is_meta(),
+            AnyVersionFrame::Compact(frame) => frame.data.is_meta(),
+        }
+    }
+
+    /// Gets the target Sensor ID for this frame.
+    /// This takes care of handling metadata frames correctly, as they embed their target IDs.
+    #[must_use]
+    pub fn target(&self) -> SensorId {
+        match self {
+            AnyVersionFrame::Version1(frame) => frame.data.target(),
+            AnyVersionFrame::Version2(frame) => frame.data.target(),
+            AnyVersionFrame::Batch(frame) => frame.data.target(),
+            AnyVersionFrame::Compact(frame) => frame.data.target(),
+        }
+    }
+
+    /// Converts the frame into a version 2 frame, upgrading version 1 frames if needed.
+    ///
+    /// Returns `None` for batches, which carry multiple readings, and for compact frames,
+    /// whose header needs to be restored by a [`CompactDecoder`](crate::versions::CompactDecoder).
+    #[must_use]
+    pub fn into_version2(self) -> Option<VersionedDataFrame<Version2, Version2DataFrame>> {
+        match self {
+            AnyVersionFrame::Version1(frame) => Some(frame.into()),
+            AnyVersionFrame::Version2(frame) => Some(frame),
+            AnyVersionFrame::Batch(_) | AnyVersionFrame::Compact(_) => None,
+        }
+    }
+}
+
+/// Returns the larger of two lengths.
+const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+impl From<VersionedDataFrame<Version1, Version1DataFrame>> for AnyVersionFrame {
+    fn from(value: VersionedDataFrame<Version1, Version1DataFrame>) -> Self {
+        AnyVersionFrame::Version1(value)
+    }
+}
+
+impl From<VersionedDataFrame<Version2, Version2DataFrame>> for AnyVersionFrame {
+    fn from(value: VersionedDataFrame<Version2, Version2DataFrame>) -> Self {
+        AnyVersionFrame::Version2(value)
+    }
+}
+
+impl From<VersionedDataFrame<Version2, BatchDataFrame<MAX_BATCH_LEN>>> for AnyVersionFrame {
+    fn from(value: VersionedDataFrame<Version2, BatchDataFrame<MAX_BATCH_LEN>>) -> Self {
+        AnyVersionFrame::Batch(value)
+    }
+}
+
+impl From<VersionedDataFrame<Version2, CompactDataFrame>> for AnyVersionFrame {
+    fn from(value: VersionedDataFrame<Version2, CompactDataFrame>) -> Self {
+        AnyVersionFrame::Compact(value)
+    }
+}
+
+/// Extracts the sensor reading; fails for batches, which carry multiple readings.
+impl TryFrom<AnyVersionFrame> for SensorData {
+    type Error = ();
+
+    fn try_from(value: AnyVersionFrame) -> Result<Self, Self::Error> {
+        match value {
+            AnyVersionFrame::Version1(frame) => Ok(frame.data.value),
+            AnyVersionFrame::Version2(frame) => Ok(frame.data.value),
+            AnyVersionFrame::Batch(_) => Err(()),
+            AnyVersionFrame::Compact(frame) => Ok(frame.data.value),
+        }
+    }
+}
@@ -0,0 +1,668 @@
+//! A batch data frame, carrying consecutive samples of a single sensor.
+
+use crate::serializer::SERIALIZATION_CONFIG;
+use crate::versions::{FrameKind, TimeBase, Version2, Version2DataFrame};
+use crate::{
+    DataFrame, DeserializationError, MaxEncodedLen, OpaquePayload, SensorData, SensorId, ValueType,
+};
+use bincode::de::read::Reader;
+use bincode::de::{Decoder, DecoderImpl};
+use bincode::enc::write::SliceWriter;
+use bincode::enc::{Encoder, EncoderImpl};
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{Decode, Encode};
+
+/// The largest number of sample bytes a batch can carry, as limited by the length byte of
+/// the encoded samples.
+pub const MAX_BATCH_LEN: usize = u8::MAX as usize;
+
+/// A data frame carrying up to `N` bytes of consecutive samples of a single sensor.
+///
+/// Only the first sample's timestamp and sensor sequence are transmitted; the samples are
+/// assumed to be taken at a fixed [`interval_nanos`](Self::interval_nanos) and to have
+/// consecutive sensor sequence identifiers. The samples are encoded without their sensor
+/// type information, which is transmitted only once per batch.
+///
+/// Use a [`BatchAccumulator`] to collect samples on the device, and [`BatchDataFrame::frames`]
+/// to expand a received batch back into individual frames. On the wire, the frame is tagged as
+/// [`FrameKind::Batch`].
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BatchDataFrame<const N: usize> {
+    /// A monotonic timestamp of the first sample in nanoseconds, relative to the
+    /// [`time_base`](Self::time_base).
+    ///
+    /// If unsupported, set to [`u64::MAX`].
+    pub timestamp_nanos: u64,
+
+    /// The epoch the timestamp is relative to.
+    pub time_base: TimeBase,
+
+    /// The time between two consecutive samples, in nanoseconds.
+    pub interval_nanos: u32,
+
+    /// A sequence identifier, monotonically increasing.
+    ///
+    /// This value can be used to detect package loss on the receiver side. It should increase
+    /// on every transmitted package, across all sensor.
+    ///
+    /// If unsupported, set to [`u32::MAX`].
+    pub global_sequence: u32,
+
+    /// The sensor sequence identifier of the first sample.
+    ///
+    /// If unsupported, set to [`u32::MAX`].
+    pub sensor_sequence: u32,
+
+    /// A device-specific tag for a specific sensor.
+    pub sensor_tag: u16,
+
+    /// The sensor type ID of the samples.
+    sensor_type_id: u8,
+
+    /// The value type of the samples.
+    value_type: ValueType,
+
+    /// The number of samples.
+    count: u8,
+
+    /// The encoded samples.
+    samples: OpaquePayload<N>,
+}
+
+impl<const N: usize> BatchDataFrame<N> {
+    /// Creates a new, empty batch.
+    ///
+    /// The sensor type of the batch is determined by the first sample that is pushed.
+    #[must_use]
+    pub fn new(
+        timestamp_nanos: u64,
+        time_base: TimeBase,
+        interval_nanos: u32,
+        global_sequence: u32,
+        sensor_sequence: u32,
+        sensor_tag: u16,
+    ) -> Self {
+        Self {
+            timestamp_nanos,
+            time_base,
+            interval_nanos,
+            global_sequence,
+            sensor_sequence,
+            sensor_tag,
+            sensor_type_id: 0,
+            value_type: ValueType::default(),
+            count: 0,
+            samples: OpaquePayload::default(),
+        }
+    }
+
+    /// Returns the number of samples.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.count as usize
+    }
+
+    /// Indicates whether the batch holds no samples.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the [`SensorId`] of the samples.
+    #[must_use]
+    pub const fn sensor_id(&self) -> SensorId {
+        SensorId::new_with(self.sensor_tag, self.sensor_type_id, self.value_type)
+    }
+
+    /// Appends a sample to the batch.
+    ///
+    /// ## Errors
+    /// Returns an error if the sample cannot be batched, if its sensor type differs from
+    /// the type of the samples already in the batch, or if the batch is full.
+    pub fn push<D>(&mut self, value: D) -> Result<(), BatchError>
+    where
+        D: Into<SensorData>,
+    {
+        self.push_value(&value.into())
+    }
+
+    /// Appends a sample to the batch.
+    fn push_value(&mut self, value: &SensorData) -> Result<(), BatchError> {
+        // Vendor payloads have no fixed length, and metadata is not periodic.
+        if value.is_vendor() || value.is_meta() {
+            return Err(BatchError::Unsupported);
+        }
+
+        if self.is_empty() {
+            self.sensor_type_id = value.sensor_type_id();
+            self.value_type = value.value_type();
+        } else if self.sensor_type_id != value.sensor_type_id()
+            || self.value_type != value.value_type()
+        {
+            return Err(BatchError::SensorMismatch);
+        }
+
+        if self.count == u8::MAX {
+            return Err(BatchError::Full);
+        }
+
+        let spare = self.samples.spare_capacity_mut();
+        let mut encoder = EncoderImpl::new(SliceWriter::new(spare), SERIALIZATION_CONFIG);
+        match value.encode_value(&mut encoder) {
+            Ok(()) => {
+                let num_written = encoder.into_writer().bytes_written();
+                self.samples.advance(num_written);
+                self.count += 1;
+                Ok(())
+            }
+            Err(err) => {
+                spare.fill(0);
+                match err {
+                    EncodeError::UnexpectedEnd => Err(BatchError::Full),
+                    err => Err(BatchError::BincodeError(err)),
+                }
+            }
+        }
+    }
+
+    /// Returns an iterator expanding the batch into individual frames.
+    ///
+    /// Timestamps are reconstructed from the timestamp of the first sample and the sample
+    /// interval, and sensor sequence identifiers are counted up from the first sample's.
+    /// All frames share the batch's global sequence identifier.
+    #[must_use]
+    pub fn frames(&self) -> BatchFrames<'_, N> {
+        BatchFrames {
+            batch: self,
+            reader: SampleReader {
+                data: self.samples.as_slice(),
+            },
+            index: 0,
+        }
+    }
+}
+
+impl<const N: usize> DataFrame for BatchDataFrame<N> {
+    type ProtocolVersion = Version2;
+
+    fn is_meta(&self) -> bool {
+        false
+    }
+
+    fn target(&self) -> SensorId {
+        self.sensor_id()
+    }
+}
+
+impl<const N: usize> MaxEncodedLen for BatchDataFrame<N> {
+    const MAX_ENCODED_LEN: usize = FrameKind::MAX_ENCODED_LEN
+        + u64::MAX_ENCODED_LEN
+        + TimeBase::MAX_ENCODED_LEN
+        + u32::MAX_ENCODED_LEN
+        + u32::MAX_ENCODED_LEN
+        + u32::MAX_ENCODED_LEN
+        + u16::MAX_ENCODED_LEN
+        + u8::MAX_ENCODED_LEN
+        + ValueType::MAX_ENCODED_LEN
+        + u8::MAX_ENCODED_LEN
+        + OpaquePayload::<N>::MAX_ENCODED_LEN;
+}
+
+impl<const N: usize> Encode for BatchDataFrame<N> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        Encode::encode(&FrameKind::Batch, encoder)?;
+        Encode::encode(&self.timestamp_nanos, encoder)?;
+        Encode::encode(&self.time_base, encoder)?;
+        Encode::encode(&self.interval_nanos, encoder)?;
+        Encode::encode(&self.global_sequence, encoder)?;
+        Encode::encode(&self.sensor_sequence, encoder)?;
+        Encode::encode(&self.sensor_tag, encoder)?;
+        Encode::encode(&self.sensor_type_id, encoder)?;
+        Encode::encode(&self.value_type, encoder)?;
+        Encode::encode(&self.count, encoder)?;
+        Encode::encode(&self.samples, encoder)
+    }
+}
+
+impl<const N: usize> Decode for BatchDataFrame<N> {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        FrameKind::Batch.decode_expected(decoder)?;
+        let frame = Self {
+            timestamp_nanos: Decode::decode(decoder)?,
+            time_base: Decode::decode(decoder)?,
+            interval_nanos: Decode::decode(decoder)?,
+            global_sequence: Decode::decode(decoder)?,
+            sensor_sequence: Decode::decode(decoder)?,
+            sensor_tag: Decode::decode(decoder)?,
+            sensor_type_id: Decode::decode(decoder)?,
+            value_type: Decode::decode(decoder)?,
+            count: Decode::decode(decoder)?,
+            samples: Decode::decode(decoder)?,
+        };
+
+        if !frame.is_empty() && !is_batchable(frame.sensor_type_id, frame.value_type) {
+            return Err(DecodeError::Other("Unsupported batch sensor type"));
+        }
+        Ok(frame)
+    }
+}
+
+/// Determines whether samples of the specified sensor type can be batched.
+///
+/// This is the rule enforced when pushing samples: vendor payloads have no fixed length,
+/// metadata is not periodic, and unknown sensor types cannot be decoded.
+fn is_batchable(sensor_type_id: u8, value_type: ValueType) -> bool {
+    !SensorData::VENDOR_TYPE_IDS.contains(&sensor_type_id)
+        && !matches!(value_type, ValueType::LinearRange | ValueType::Identifier)
+        && SensorData::components(sensor_type_id, value_type).is_ok()
+}
+
+/// A batching error.
+#[derive(Debug)]
+pub enum BatchError {
+    /// Vendor-specific readings and metadata cannot be batched.
+    Unsupported,
+    /// The sample's sensor type differs from the batch.
+    SensorMismatch,
+    /// The batch cannot hold any more samples.
+    Full,
+    /// A bincode encoding error occurred.
+    BincodeError(EncodeError),
+}
+
+impl core::fmt::Display for BatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BatchError::Unsupported => f.write_str("sensor type cannot be batched"),
+            BatchError::SensorMismatch => f.write_str("sensor type differs from batch"),
+            BatchError::Full => f.write_str("batch full"),
+            BatchError::BincodeError(err) => core::fmt::Display::fmt(&err, f),
+        }
+    }
+}
+
+/// Collects consecutive samples of a sensor into [`BatchDataFrame`] instances.
+///
+/// ```
+/// # use serial_sensors_proto::{serialize, Vector3Data};
+/// # use serial_sensors_proto::types::AccelerometerI16;
+/// # use serial_sensors_proto::versions::{BatchAccumulator, TimeBase};
+/// let mut accumulator = BatchAccumulator::<60>::new(0, TimeBase::BootRelative, 1_000_000);
+/// for sequence in 0..11 {
+///     let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+///     let timestamp = u64::from(sequence) * 1_000_000;
+///     if let Some(batch) = accumulator.push(timestamp, sequence, sequence, value).unwrap() {
+///         // The eleventh sample no longer fits and starts a new batch.
+///         assert_eq!(batch.len(), 10);
+///
+///         let mut buffer = [0_u8; 200];
+///         let range = serialize(batch, &mut buffer).unwrap();
+///     }
+/// }
+///
+/// let batch = accumulator.flush().unwrap();
+/// assert_eq!(batch.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchAccumulator<const N: usize> {
+    /// The tag of the sensor.
+    sensor_tag: u16,
+    /// The epoch of the timestamps.
+    time_base: TimeBase,
+    /// The time between two samples, in nanoseconds.
+    interval_nanos: u32,
+    /// The batch currently being collected.
+    batch: Option<BatchDataFrame<N>>,
+}
+
+impl<const N: usize> BatchAccumulator<N> {
+    /// Creates a new accumulator for samples taken at the specified interval.
+    #[must_use]
+    pub const fn new(sensor_tag: u16, time_base: TimeBase, interval_nanos: u32) -> Self {
+        Self {
+            sensor_tag,
+            time_base,
+            interval_nanos,
+            batch: None,
+        }
+    }
+
+    /// Returns the number of samples in the current batch.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.batch.as_ref().map_or(0, BatchDataFrame::len)
+    }
+
+    /// Indicates whether the current batch holds no samples.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds a sample to the current batch.
+    ///
+    /// If the sample cannot be added, because the batch is full, the sensor type changed,
+    /// or the sensor sequence is not consecutive, the current batch is returned and the
+    /// sample starts a new one. The timestamp and global sequence are only used for the
+    /// first sample of each batch.
+    ///
+    /// ## Errors
+    /// Returns an error if the sample cannot be batched, or if it exceeds the capacity
+    /// of an empty batch.
+    pub fn push<D>(
+        &mut self,
+        timestamp_nanos: u64,
+        global_sequence: u32,
+        sensor_sequence: u32,
+        value: D,
+    ) -> Result<Option<BatchDataFrame<N>>, BatchError>
+    where
+        D: Into<SensorData>,
+    {
+        let value = value.into();
+        if let Some(batch) = &mut self.batch {
+            let expected_sequence = batch.sensor_sequence.wrapping_add(u32::from(batch.count));
+            if expected_sequence == sensor_sequence {
+                match batch.push_value(&value) {
+                    Ok(()) => return Ok(None),
+                    Err(BatchError::Full | BatchError::SensorMismatch) => {}
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+
+        let mut batch = BatchDataFrame::new(
+            timestamp_nanos,
+            self.time_base,
+            self.interval_nanos,
+            global_sequence,
+            sensor_sequence,
+            self.sensor_tag,
+        );
+        batch.push_value(&value)?;
+        Ok(self.batch.replace(batch))
+    }
+
+    /// Takes the current batch, if it holds any samples.
+    pub fn flush(&mut self) -> Option<BatchDataFrame<N>> {
+        self.batch.take()
+    }
+}
+
+/// An iterator over the individual frames of a batch. See [`BatchDataFrame::frames`].
+#[derive(Debug)]
+pub struct BatchFrames<'a, const N: usize> {
+    batch: &'a BatchDataFrame<N>,
+    reader: SampleReader<'a>,
+    index: u8,
+}
+
+impl<const N: usize> Iterator for BatchFrames<'_, N> {
+    type Item = Result<Version2DataFrame, DeserializationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.batch.count {
+            // Trailing bytes indicate that the samples were not decoded correctly.
+            if self.reader.data.is_empty() {
+                return None;
+            }
+            self.reader.data = &[];
+            return Some(Err(DeserializationError::Corrupt));
+        }
+
+        let mut decoder = DecoderImpl::new(&mut self.reader, SERIALIZATION_CONFIG);
+        let value = match SensorData::decode_value(
+            self.batch.sensor_type_id,
+            self.batch.value_type,
+            &mut decoder,
+        ) {
+            Ok(value) => value,
+            Err(err) => {
+                self.index = self.batch.count;
+                self.reader.data = &[];
+                return Some(Err(err.into()));
+            }
+        };
+
+        let index = self.index;
+        self.index += 1;
+
+        let timestamp_nanos = if self.batch.timestamp_nanos == u64::MAX {
+            u64::MAX
+        } else {
+            self.batch.timestamp_nanos.saturating_add(
+                u64::from(self.batch.interval_nanos).saturating_mul(u64::from(index)),
+            )
+        };
+        let sensor_sequence = if self.batch.sensor_sequence == u32::MAX {
+            u32::MAX
+        } else {
+            self.batch.sensor_sequence.wrapping_add(u32::from(index))
+        };
+
+        Some(Ok(Version2DataFrame::new_with(
+            timestamp_nanos,
+            self.batch.time_base,
+            self.batch.global_sequence,
+            sensor_sequence,
+            self.batch.sensor_tag,
+            value,
+        )))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::from(self.batch.count - self.index);
+        (0, Some(remaining + 1))
+    }
+}
+
+/// A reader over the encoded samples that advances as it reads.
+#[derive(Debug)]
+struct SampleReader<'a> {
+    data: &'a [u8],
+}
+
+impl Reader for SampleReader<'_> {
+    fn read(&mut self, bytes: &mut [u8]) -> Result<(), DecodeError> {
+        if bytes.len() > self.data.len() {
+            return Err(DecodeError::UnexpectedEnd {
+                additional: bytes.len() - self.data.len(),
+            });
+        }
+
+        let (head, tail) = self.data.split_at(bytes.len());
+        bytes.copy_from_slice(head);
+        self.data = tail;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AccelerometerI16, GyroscopeI16, TemperatureI16};
+    use crate::{ScalarData, Vector3Data, VersionedDataFrame};
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_serialization() {
+        let mut batch = BatchDataFrame::<64>::new(5_000, TimeBase::Unix, 1_000_000, 7, 100, 2);
+        for x in 0..3 {
+            batch
+                .push(AccelerometerI16::new(Vector3Data { x, y: -1, z: 2 }))
+                .expect("Failed to push");
+        }
+        assert_eq!(batch.len(), 3);
+
+        let mut buffer = [0_u8; 128];
+        let num_serialized = bincode::encode_into_slice(
+            batch.clone().into_versioned(),
+            &mut buffer,
+            SERIALIZATION_CONFIG,
+        )
+        .expect("Failed to encode");
+        assert_eq!(
+            num_serialized,
+            1 // version
+                + 1 // frame kind
+                + 8 // time
+                + 1 // time base
+                + 4 // interval
+                + 4 // global sequence
+                + 4 // sensor sequence
+                + 2 // sensor tag
+                + 1 // sensor type
+                + 1 // data type
+                + 1 // sample count
+                + 1 // sample length
+                + 3 * 3 * 2 // samples
+        );
+        assert_eq!(buffer[..2], [2, FrameKind::Batch as u8]);
+
+        let (value, _): (VersionedDataFrame<Version2, BatchDataFrame<64>>, _) =
+            bincode::decode_from_slice(&buffer[..num_serialized], SERIALIZATION_CONFIG)
+                .expect("Failed to decode");
+        assert_eq!(value.data, batch);
+        assert_eq!(
+            value.data.target(),
+            SensorId::new_with(2, 0x42, ValueType::SInt16)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_frames() {
+        let mut batch = BatchDataFrame::<64>::new(5_000, TimeBase::Unix, 1_000, 7, 100, 2);
+        for value in [21, 22, 23] {
+            batch
+                .push(TemperatureI16::new(ScalarData::new(value)))
+                .expect("Failed to push");
+        }
+
+        let mut count = 0;
+        for (index, frame) in batch.frames().enumerate() {
+            let frame = frame.expect("Failed to decode");
+            let offset = u32::try_from(index).expect("Invalid index");
+            assert_eq!(frame.timestamp_nanos, 5_000 + 1_000 * u64::from(offset));
+            assert_eq!(frame.time_base, TimeBase::Unix);
+            assert_eq!(frame.global_sequence, 7);
+            assert_eq!(frame.sensor_sequence, 100 + offset);
+            assert_eq!(frame.sensor_tag, 2);
+
+            let value: TemperatureI16 = frame.try_into().expect("Invalid type");
+            assert_eq!(
+                value.value,
+                21 + i16::try_from(offset).expect("Invalid offset")
+            );
+            count += 1;
+        }
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_push_errors() {
+        let mut batch = BatchDataFrame::<8>::new(0, TimeBase::BootRelative, 1_000, 0, 0, 0);
+        assert!(batch
+            .push(AccelerometerI16::new(Vector3Data::default()))
+            .is_ok());
+        assert!(matches!(
+            batch.push(GyroscopeI16::new(Vector3Data::default())),
+            Err(BatchError::SensorMismatch)
+        ));
+        assert!(matches!(
+            batch.push(AccelerometerI16::new(Vector3Data::default())),
+            Err(BatchError::Full)
+        ));
+        assert_eq!(batch.len(), 1);
+
+        let vendor = SensorData::vendor(0xC0, ValueType::UInt8, &[1]).expect("Invalid vendor data");
+        assert!(matches!(batch.push(vendor), Err(BatchError::Unsupported)));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_accumulator() {
+        let mut accumulator = BatchAccumulator::<12>::new(3, TimeBase::BootRelative, 1_000);
+        let value = AccelerometerI16::new(Vector3Data::default());
+
+        // The third sample does not fit.
+        assert!(accumulator
+            .push(0, 1, 10, value)
+            .expect("Failed to push")
+            .is_none());
+        assert!(accumulator
+            .push(1_000, 2, 11, value)
+            .expect("Failed to push")
+            .is_none());
+        let batch = accumulator
+            .push(2_000, 3, 12, value)
+            .expect("Failed to push")
+            .expect("Expected a batch");
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.global_sequence, 1);
+        assert_eq!(batch.sensor_sequence, 10);
+
+        // A gap in the sensor sequence starts a new batch.
+        let batch = accumulator
+            .push(4_000, 4, 14, value)
+            .expect("Failed to push")
+            .expect("Expected a batch");
+        assert_eq!(batch.len(), 1);
+        assert_eq!(batch.timestamp_nanos, 2_000);
+
+        let batch = accumulator.flush().expect("Expected a batch");
+        assert_eq!(batch.sensor_sequence, 14);
+        assert!(accumulator.is_empty());
+        assert!(accumulator.flush().is_none());
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_decode_unsupported_type() {
+        let mut batch = BatchDataFrame::<64>::new(0, TimeBase::BootRelative, 1_000, 0, 0, 0);
+        for value in [1, 2, 3] {
+            batch
+                .push(TemperatureI16::new(ScalarData::new(value)))
+                .expect("Failed to push");
+        }
+
+        let mut buffer = [0_u8; 128];
+        let num_serialized =
+            bincode::encode_into_slice(batch.into_versioned(), &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to encode");
+
+        // The sensor type ID follows the version, kind, timestamp, interval, sequences and tag.
+        let type_offset = 1 + 1 + 8 + 1 + 4 + 4 + 4 + 2;
+        for (type_id, value_type) in [
+            (0xEE, ValueType::SInt16),
+            (0xC0, ValueType::SInt16),
+            (0xFE, ValueType::LinearRange),
+        ] {
+            buffer[type_offset] = type_id;
+            buffer[type_offset + 1] = value_type as u8;
+            let result: Result<(VersionedDataFrame<Version2, BatchDataFrame<64>>, _), _> =
+                bincode::decode_from_slice(&buffer[..num_serialized], SERIALIZATION_CONFIG);
+            assert!(matches!(result, Err(DecodeError::Other(_))));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_corrupt_sample_count() {
+        let mut batch = BatchDataFrame::<64>::new(0, TimeBase::BootRelative, 1_000, 0, 0, 0);
+        batch
+            .push(TemperatureI16::new(ScalarData::new(1)))
+            .expect("Failed to push");
+        batch.count = 0;
+
+        let mut frames = batch.frames();
+        assert!(matches!(
+            frames.next(),
+            Some(Err(DeserializationError::Corrupt))
+        ));
+        assert!(frames.next().is_none());
+    }
+}
@@ -0,0 +1,758 @@
+//! A compact data frame with delta-coded headers.
+
+use crate::versions::{FrameKind, TimeBase, Version2, Version2DataFrame};
+use crate::{DataFrame, DeserializationError, MaxEncodedLen, SensorData, SensorId};
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::write::Writer;
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+
+/// Flag in the marker byte indicating a keyframe.
+const KEYFRAME_FLAG: u8 = 0x80;
+
+/// Mask of the frame counter in the marker byte.
+const COUNTER_MASK: u8 = 0x7F;
+
+/// The default number of sensors whose headers are tracked for delta coding.
+const DEFAULT_SENSORS: usize = 8;
+
+/// A data frame whose header fields are delta-coded against the previous frame of the same sensor.
+///
+/// Header fields are encoded as variable-length integers (LEB128), with differences to the
+/// previous frame being zigzag-encoded. Keyframes carry the absolute header values and allow
+/// a receiver to (re)synchronize; the sensor reading itself is encoded as usual.
+///
+/// Compact frames are created by a [`CompactEncoder`] and turned back into
+/// [`Version2DataFrame`] instances by a [`CompactDecoder`]. Each frame carries a 7-bit
+/// counter that allows the decoder to detect lost frames. On the wire, the frame is tagged as
+/// [`FrameKind::Compact`].
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CompactDataFrame {
+    /// The frame counter.
+    counter: u8,
+    /// The header fields.
+    header: CompactHeader,
+    /// The sensor reading.
+    pub(crate) value: SensorData,
+}
+
+/// The header of a [`CompactDataFrame`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+enum CompactHeader {
+    /// Absolute header values.
+    Keyframe(FrameHeader),
+    /// Differences to the previous frame.
+    Delta {
+        /// The timestamp difference, in nanoseconds.
+        timestamp_nanos: i64,
+        /// The global sequence difference.
+        global_sequence: i32,
+        /// The sensor sequence difference.
+        sensor_sequence: i32,
+        /// The sensor tag.
+        sensor_tag: u16,
+    },
+}
+
+/// The header fields of a [`Version2DataFrame`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct FrameHeader {
+    timestamp_nanos: u64,
+    time_base: TimeBase,
+    global_sequence: u32,
+    sensor_sequence: u32,
+    sensor_tag: u16,
+}
+
+impl From<&Version2DataFrame> for FrameHeader {
+    fn from(value: &Version2DataFrame) -> Self {
+        Self {
+            timestamp_nanos: value.timestamp_nanos,
+            time_base: value.time_base,
+            global_sequence: value.global_sequence,
+            sensor_sequence: value.sensor_sequence,
+            sensor_tag: value.sensor_tag,
+        }
+    }
+}
+
+/// The header of the most recent frame of a sensor.
+#[derive(Debug, Clone)]
+struct SensorHeader {
+    sensor: SensorId,
+    header: FrameHeader,
+    /// The number of frames of the sensor sent since its last keyframe.
+    since_keyframe: u16,
+}
+
+/// The headers of the most recent frames, keyed by sensor.
+#[derive(Debug, Clone)]
+struct SensorHeaders<const N: usize> {
+    entries: [Option<SensorHeader>; N],
+    /// The entry that is replaced next when all entries are in use.
+    next: usize,
+}
+
+impl<const N: usize> SensorHeaders<N> {
+    const EMPTY: Option<SensorHeader> = None;
+
+    const fn new() -> Self {
+        Self {
+            entries: [Self::EMPTY; N],
+            next: 0,
+        }
+    }
+
+    fn get(&self, sensor: &SensorId) -> Option<&SensorHeader> {
+        self.entries
+            .iter()
+            .flatten()
+            .find(|entry| entry.sensor == *sensor)
+    }
+
+    /// Stores the header of a sensor, replacing the entries of other sensors in turn
+    /// when all entries are in use.
+    fn insert(&mut self, sensor: SensorId, header: FrameHeader, since_keyframe: u16) {
+        if N == 0 {
+            return;
+        }
+
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.as_ref().is_some_and(|entry| entry.sensor == sensor))
+            .or_else(|| self.entries.iter().position(Option::is_none))
+            .unwrap_or_else(|| {
+                let index = self.next;
+                self.next = (index + 1) % N;
+                index
+            });
+
+        self.entries[index] = Some(SensorHeader {
+            sensor,
+            header,
+            since_keyframe,
+        });
+    }
+
+    fn clear(&mut self) {
+        self.entries = [Self::EMPTY; N];
+        self.next = 0;
+    }
+}
+
+/// Returns the sensor whose previous frame a frame is delta-coded against.
+fn sensor_of(sensor_tag: u16, value: &SensorData) -> SensorId {
+    SensorId::new_with(sensor_tag, value.sensor_type_id(), value.value_type())
+}
+
+impl CompactDataFrame {
+    /// Indicates whether this frame is a keyframe.
+    #[must_use]
+    pub const fn is_keyframe(&self) -> bool {
+        matches!(self.header, CompactHeader::Keyframe(_))
+    }
+
+    /// Returns the sensor reading.
+    #[must_use]
+    pub const fn value(&self) -> &SensorData {
+        &self.value
+    }
+
+    /// Returns the sensor tag.
+    #[must_use]
+    pub const fn sensor_tag(&self) -> u16 {
+        match &self.header {
+            CompactHeader::Keyframe(header) => header.sensor_tag,
+            CompactHeader::Delta { sensor_tag, .. } => *sensor_tag,
+        }
+    }
+}
+
+impl DataFrame for CompactDataFrame {
+    type ProtocolVersion = Version2;
+
+    fn is_meta(&self) -> bool {
+        self.value.is_meta()
+    }
+
+    fn target(&self) -> SensorId {
+        self.value.meta_target().cloned().unwrap_or_else(|| {
+            SensorId::new_with(
+                self.sensor_tag(),
+                self.value.sensor_type_id(),
+                self.value.value_type(),
+            )
+        })
+    }
+}
+
+impl Encode for CompactDataFrame {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        Encode::encode(&FrameKind::Compact, encoder)?;
+        match &self.header {
+            CompactHeader::Keyframe(header) => {
+                Encode::encode(&(KEYFRAME_FLAG | self.counter), encoder)?;
+                encode_varint(header.timestamp_nanos, encoder)?;
+                Encode::encode(&header.time_base, encoder)?;
+                encode_varint(u64::from(header.global_sequence), encoder)?;
+                encode_varint(u64::from(header.sensor_sequence), encoder)?;
+                encode_varint(u64::from(header.sensor_tag), encoder)?;
+            }
+            CompactHeader::Delta {
+                timestamp_nanos,
+                global_sequence,
+                sensor_sequence,
+                sensor_tag,
+            } => {
+                Encode::encode(&self.counter, encoder)?;
+                encode_varint(zigzag(*timestamp_nanos), encoder)?;
+                encode_varint(zigzag(i64::from(*global_sequence)), encoder)?;
+                encode_varint(zigzag(i64::from(*sensor_sequence)), encoder)?;
+                encode_varint(u64::from(*sensor_tag), encoder)?;
+            }
+        }
+        Encode::encode(&self.value, encoder)
+    }
+}
+
+impl MaxEncodedLen for CompactDataFrame {
+    // Keyframes carry the longest header; delta-coded values never exceed the varint
+    // length of their absolute counterparts.
+    const MAX_ENCODED_LEN: usize = FrameKind::MAX_ENCODED_LEN
+        + u8::MAX_ENCODED_LEN
+        + max_varint_len(u64::BITS)
+        + TimeBase::MAX_ENCODED_LEN
+        + max_varint_len(u32::BITS)
+        + max_varint_len(u32::BITS)
+        + max_varint_len(u16::BITS)
+        + SensorData::MAX_ENCODED_LEN;
+}
+
+impl Decode for CompactDataFrame {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        FrameKind::Compact.decode_expected(decoder)?;
+        let marker: u8 = Decode::decode(decoder)?;
+        let header = if marker & KEYFRAME_FLAG == 0 {
+            CompactHeader::Delta {
+                timestamp_nanos: unzigzag(decode_varint(decoder)?),
+                global_sequence: narrow(unzigzag(decode_varint(decoder)?))?,
+                sensor_sequence: narrow(unzigzag(decode_varint(decoder)?))?,
+                sensor_tag: narrow(decode_varint(decoder)?)?,
+            }
+        } else {
+            CompactHeader::Keyframe(FrameHeader {
+                timestamp_nanos: decode_varint(decoder)?,
+                time_base: Decode::decode(decoder)?,
+                global_sequence: narrow(decode_varint(decoder)?)?,
+                sensor_sequence: narrow(decode_varint(decoder)?)?,
+                sensor_tag: narrow(decode_varint(decoder)?)?,
+            })
+        };
+
+        Ok(Self {
+            counter: marker & COUNTER_MASK,
+            header,
+            value: Decode::decode(decoder)?,
+        })
+    }
+}
+
+impl<'de> BorrowDecode<'de> for CompactDataFrame {
+    fn borrow_decode<D: BorrowDecoder<'de>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        CompactDataFrame::decode(decoder)
+    }
+}
+
+/// Turns [`Version2DataFrame`] instances into [`CompactDataFrame`] instances.
+///
+/// Headers are delta-coded against the previous frame of the same sensor, so that interleaved
+/// sensors do not inflate each other's deltas. The headers of up to `N` sensors are tracked;
+/// beyond that, sensors replace each other in turn and their next frame is sent as a keyframe.
+///
+/// The first frame of a sensor, every `keyframe_interval`-th frame of it thereafter and frames
+/// whose [`TimeBase`] changed are sent as keyframes. A keyframe can also be requested explicitly,
+/// e.g. when a receiver connects.
+///
+/// ```
+/// # use serial_sensors_proto::{serialize, Vector3Data};
+/// # use serial_sensors_proto::types::AccelerometerI16;
+/// # use serial_sensors_proto::versions::{CompactEncoder, TimeBase, Version2DataFrame};
+/// let mut encoder: CompactEncoder = CompactEncoder::new(100);
+/// let mut buffer = [0_u8; 64];
+/// for sequence in 0..3 {
+///     let value = AccelerometerI16::new(Vector3Data { x: 1, y: -2, z: 3 });
+///     let timestamp = u64::from(sequence) * 1_000_000;
+///     let frame = Version2DataFrame::new(timestamp, TimeBase::BootRelative, sequence, sequence, 0, value);
+///
+///     let range = serialize(encoder.encode(frame), &mut buffer).unwrap();
+///     assert!(range.len() < 29);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CompactEncoder<const N: usize = DEFAULT_SENSORS> {
+    /// The number of frames of a sensor after which a keyframe is sent; zero disables
+    /// periodic keyframes.
+    keyframe_interval: u16,
+    /// The frame counter.
+    counter: u8,
+    /// The headers of the previous frames; sensors without an entry are due a keyframe.
+    previous: SensorHeaders<N>,
+}
+
+impl<const N: usize> CompactEncoder<N> {
+    /// Creates a new encoder that sends a keyframe every `keyframe_interval` frames of a sensor.
+    ///
+    /// If `keyframe_interval` is zero, keyframes are only sent for the first frame of a sensor
+    /// and when requested via [`request_keyframe`](Self::request_keyframe).
+    #[must_use]
+    pub const fn new(keyframe_interval: u16) -> Self {
+        Self {
+            keyframe_interval,
+            counter: 0,
+            previous: SensorHeaders::new(),
+        }
+    }
+
+    /// Ensures that the next frame of every sensor is sent as a keyframe.
+    pub fn request_keyframe(&mut self) {
+        self.previous.clear();
+    }
+
+    /// Encodes the specified frame.
+    pub fn encode<F>(&mut self, frame: F) -> CompactDataFrame
+    where
+        F: Into<Version2DataFrame>,
+    {
+        let frame = frame.into();
+        let current = FrameHeader::from(&frame);
+        let sensor = sensor_of(frame.sensor_tag, &frame.value);
+
+        let (header, since_keyframe) = match self.previous.get(&sensor) {
+            Some(SensorHeader {
+                header: previous,
+                since_keyframe,
+                ..
+            }) if (self.keyframe_interval == 0 || *since_keyframe < self.keyframe_interval)
+                && previous.time_base == current.time_base =>
+            {
+                let header = CompactHeader::Delta {
+                    timestamp_nanos: reinterpret_u64(
+                        current
+                            .timestamp_nanos
+                            .wrapping_sub(previous.timestamp_nanos),
+                    ),
+                    global_sequence: reinterpret_u32(
+                        current
+                            .global_sequence
+                            .wrapping_sub(previous.global_sequence),
+                    ),
+                    sensor_sequence: reinterpret_u32(
+                        current
+                            .sensor_sequence
+                            .wrapping_sub(previous.sensor_sequence),
+                    ),
+                    sensor_tag: current.sensor_tag,
+                };
+                (header, since_keyframe.saturating_add(1))
+            }
+            _ => (CompactHeader::Keyframe(current), 1),
+        };
+
+        let counter = self.counter;
+        self.counter = self.counter.wrapping_add(1) & COUNTER_MASK;
+        self.previous.insert(sensor, current, since_keyframe);
+
+        CompactDataFrame {
+            counter,
+            header,
+            value: frame.value,
+        }
+    }
+}
+
+/// Turns [`CompactDataFrame`] instances back into [`Version2DataFrame`] instances.
+///
+/// Delta frames of a sensor can only be decoded after a keyframe of that sensor was received.
+/// If a frame is lost, the decoder reports [`DeserializationError::MissingKeyframe`] for every
+/// sensor until its next keyframe. The decoder should track at least as many sensors as the
+/// [`CompactEncoder`].
+#[derive(Debug, Clone)]
+pub struct CompactDecoder<const N: usize = DEFAULT_SENSORS> {
+    /// The expected frame counter.
+    counter: u8,
+    /// The headers of the previous frames; sensors without an entry require a keyframe.
+    previous: SensorHeaders<N>,
+}
+
+impl<const N: usize> CompactDecoder<N> {
+    /// Creates a new decoder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            counter: 0,
+            previous: SensorHeaders::new(),
+        }
+    }
+
+    /// Discards the decoder state; subsequent delta frames are rejected until the next keyframe
+    /// of their sensor.
+    ///
+    /// This should be called when a frame could not be deserialized.
+    pub fn reset(&mut self) {
+        self.previous.clear();
+    }
+
+    /// Decodes the specified frame.
+    ///
+    /// ## Errors
+    /// Returns [`DeserializationError::MissingKeyframe`] if a delta frame is received without
+    /// a preceding keyframe of its sensor, or if a frame was lost since.
+    pub fn decode(
+        &mut self,
+        frame: CompactDataFrame,
+    ) -> Result<Version2DataFrame, DeserializationError> {
+        let sensor = sensor_of(frame.sensor_tag(), &frame.value);
+        if frame.counter != self.counter {
+            // A lost frame may have been a keyframe of any sensor.
+            self.previous.clear();
+        }
+        self.counter = frame.counter.wrapping_add(1) & COUNTER_MASK;
+
+        let header = match frame.header {
+            CompactHeader::Keyframe(header) => header,
+            CompactHeader::Delta {
+                timestamp_nanos,
+                global_sequence,
+                sensor_sequence,
+                sensor_tag,
+            } => {
+                let Some(SensorHeader {
+                    header: previous, ..
+                }) = self.previous.get(&sensor)
+                else {
+                    return Err(DeserializationError::MissingKeyframe);
+                };
+
+                FrameHeader {
+                    timestamp_nanos: previous
+                        .timestamp_nanos
+                        .wrapping_add(reinterpret_i64(timestamp_nanos)),
+                    time_base: previous.time_base,
+                    global_sequence: previous
+                        .global_sequence
+                        .wrapping_add(reinterpret_i32(global_sequence)),
+                    sensor_sequence: previous
+                        .sensor_sequence
+                        .wrapping_add(reinterpret_i32(sensor_sequence)),
+                    sensor_tag,
+                }
+            }
+        };
+
+        self.previous.insert(sensor, header, 0);
+
+        Ok(Version2DataFrame::new_with(
+            header.timestamp_nanos,
+            header.time_base,
+            header.global_sequence,
+            header.sensor_sequence,
+            header.sensor_tag,
+            frame.value,
+        ))
+    }
+}
+
+impl<const N: usize> Default for CompactDecoder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reinterprets the bits of an unsigned difference as a signed value.
+fn reinterpret_u64(value: u64) -> i64 {
+    i64::from_ne_bytes(value.to_ne_bytes())
+}
+
+/// Reinterprets the bits of an unsigned difference as a signed value.
+fn reinterpret_u32(value: u32) -> i32 {
+    i32::from_ne_bytes(value.to_ne_bytes())
+}
+
+/// Reinterprets the bits of a signed difference as an unsigned value.
+fn reinterpret_i64(value: i64) -> u64 {
+    u64::from_ne_bytes(value.to_ne_bytes())
+}
+
+/// Reinterprets the bits of a signed difference as an unsigned value.
+fn reinterpret_i32(value: i32) -> u32 {
+    u32::from_ne_bytes(value.to_ne_bytes())
+}
+
+/// Maps signed values to unsigned values such that small magnitudes remain small.
+fn zigzag(value: i64) -> u64 {
+    reinterpret_i64((value << 1) ^ (value >> 63))
+}
+
+/// Reverses [`zigzag`].
+fn unzigzag(value: u64) -> i64 {
+    reinterpret_u64(value >> 1) ^ -reinterpret_u64(value & 1)
+}
+
+/// Narrows a decoded value to the target type.
+fn narrow<T, V>(value: V) -> Result<T, DecodeError>
+where
+    T: TryFrom<V>,
+{
+    T::try_from(value).map_err(|_| DecodeError::Other("Variable-length integer out of range"))
+}
+
+/// Returns the maximum number of bytes of a LEB128-encoded value of the specified bit width.
+const fn max_varint_len(bits: u32) -> usize {
+    (bits as usize + 6) / 7
+}
+
+/// Encodes an unsigned value as LEB128.
+fn encode_varint<E: Encoder>(mut value: u64, encoder: &mut E) -> Result<(), EncodeError> {
+    loop {
+        let byte = value.to_le_bytes()[0] & 0x7F;
+        value >>= 7;
+        if value == 0 {
+            return encoder.writer().write(&[byte]);
+        }
+        encoder.writer().write(&[byte | 0x80])?;
+    }
+}
+
+/// Decodes an unsigned LEB128 value.
+fn decode_varint<D: Decoder>(decoder: &mut D) -> Result<u64, DecodeError> {
+    let mut value = 0_u64;
+    let mut shift = 0;
+    loop {
+        let byte: u8 = Decode::decode(decoder)?;
+        if shift > 63 {
+            return Err(DecodeError::Other("Variable-length integer too long"));
+        }
+        if shift == 63 && byte & 0x7E != 0 {
+            return Err(DecodeError::Other("Variable-length integer out of range"));
+        }
+
+        value |= u64::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{frame_v2, reading};
+    use crate::serializer::SERIALIZATION_CONFIG;
+
+    #[test]
+    fn test_zigzag() {
+        for (value, encoded) in [(0, 0), (-1, 1), (1, 2), (-2, 3), (i64::MAX, u64::MAX - 1)] {
+            assert_eq!(zigzag(value), encoded);
+            assert_eq!(unzigzag(encoded), value);
+        }
+        assert_eq!(unzigzag(u64::MAX), i64::MIN);
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_round_trip() {
+        let mut encoder = CompactEncoder::<8>::new(4);
+        let mut decoder = CompactDecoder::<8>::new();
+
+        let mut buffer = [0_u8; 64];
+        for sequence in 1..10 {
+            let compact = encoder.encode(frame_v2(sequence));
+            assert_eq!(compact.is_keyframe(), sequence % 4 == 1);
+
+            let num_serialized = bincode::encode_into_slice(
+                compact.clone().into_versioned(),
+                &mut buffer,
+                SERIALIZATION_CONFIG,
+            )
+            .expect("Failed to encode");
+            assert_eq!(buffer[..2], [2, FrameKind::Compact as u8]);
+
+            let (received, _): (crate::VersionedDataFrame<Version2, CompactDataFrame>, _) =
+                bincode::decode_from_slice(&buffer[..num_serialized], SERIALIZATION_CONFIG)
+                    .expect("Failed to decode");
+            assert_eq!(received.data, compact);
+
+            let restored = decoder.decode(received.data).expect("Failed to decode");
+            assert_eq!(restored, frame_v2(sequence));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_frame_size() {
+        let mut encoder = CompactEncoder::<8>::new(0);
+        let mut buffer = [0_u8; 64];
+
+        let keyframe = encoder.encode(frame_v2(1));
+        let num_keyframe = bincode::encode_into_slice(
+            keyframe.into_versioned(),
+            &mut buffer,
+            SERIALIZATION_CONFIG,
+        )
+        .expect("Failed to encode");
+        assert_eq!(
+            num_keyframe,
+            1 // version
+                + 1 // frame kind
+                + 1 // marker
+                + 3 // time
+                + 1 // time base
+                + 1 // global sequence
+                + 1 // sensor sequence
+                + 1 // sensor tag
+                + 1 // sensor type
+                + 1 // data type
+                + 3 * 2 // 3-axis data
+        );
+
+        let delta = encoder.encode(frame_v2(2));
+        let num_delta =
+            bincode::encode_into_slice(delta.into_versioned(), &mut buffer, SERIALIZATION_CONFIG)
+                .expect("Failed to encode");
+        assert_eq!(num_delta, num_keyframe - 1);
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_resynchronization() {
+        let mut encoder = CompactEncoder::<8>::new(0);
+        let mut decoder = CompactDecoder::<8>::new();
+
+        // Joining mid-stream requires a keyframe.
+        let _ = encoder.encode(frame_v2(1));
+        assert!(matches!(
+            decoder.decode(encoder.encode(frame_v2(2))),
+            Err(DeserializationError::MissingKeyframe)
+        ));
+
+        encoder.request_keyframe();
+        assert!(decoder.decode(encoder.encode(frame_v2(3))).is_ok());
+        assert!(decoder.decode(encoder.encode(frame_v2(4))).is_ok());
+
+        // A lost frame is detected by the frame counter.
+        let _ = encoder.encode(frame_v2(5));
+        assert!(matches!(
+            decoder.decode(encoder.encode(frame_v2(6))),
+            Err(DeserializationError::MissingKeyframe)
+        ));
+        assert!(matches!(
+            decoder.decode(encoder.encode(frame_v2(7))),
+            Err(DeserializationError::MissingKeyframe)
+        ));
+
+        encoder.request_keyframe();
+        let restored = decoder
+            .decode(encoder.encode(frame_v2(8)))
+            .expect("Failed to decode");
+        assert_eq!(restored, frame_v2(8));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_interleaved_sensors() {
+        let mut decoder = CompactDecoder::<8>::new();
+        let value = reading();
+        let interleaved = |sequence: u32| {
+            let sensor_tag = u16::from(sequence % 2 == 0);
+            let timestamp = u64::from(sequence) * 1_000_000 + u64::from(sensor_tag) * 1_000_000_000;
+            let sensor_sequence = sequence / 2 + u32::from(sensor_tag) * 100_000;
+            Version2DataFrame::new(
+                timestamp,
+                TimeBase::BootRelative,
+                sequence,
+                sensor_sequence,
+                sensor_tag,
+                value,
+            )
+        };
+
+        // Each sensor is delta-coded against its own previous frame.
+        let mut encoder = CompactEncoder::<8>::new(0);
+        let mut buffer = [0_u8; 64];
+        for sequence in 1..10 {
+            let compact = encoder.encode(interleaved(sequence));
+            assert_eq!(compact.is_keyframe(), sequence <= 2);
+            if !compact.is_keyframe() {
+                let len =
+                    bincode::encode_into_slice(compact.clone(), &mut buffer, SERIALIZATION_CONFIG)
+                        .expect("Failed to encode");
+                // The frame kind and marker precede the header.
+                assert_eq!(len, 1 + 1 + 4 + 1 + 1 + 1 + 2 + 3 * 2);
+            }
+
+            let restored = decoder.decode(compact).expect("Failed to decode");
+            assert_eq!(restored, interleaved(sequence));
+        }
+
+        // Sensors beyond the capacity replace each other and are sent as keyframes.
+        let mut encoder = CompactEncoder::<1>::new(0);
+        let mut decoder = CompactDecoder::<1>::new();
+        for sequence in 1..10 {
+            let compact = encoder.encode(interleaved(sequence));
+            assert!(compact.is_keyframe());
+            let restored = decoder.decode(compact).expect("Failed to decode");
+            assert_eq!(restored, interleaved(sequence));
+        }
+    }
+
+    #[test]
+    fn test_varint_overflow() {
+        let decode = |bytes: &[u8]| {
+            let mut decoder = bincode::de::DecoderImpl::new(
+                bincode::de::read::SliceReader::new(bytes),
+                SERIALIZATION_CONFIG,
+            );
+            decode_varint(&mut decoder)
+        };
+
+        let mut max = [0xFF_u8; 10];
+        max[9] = 0x01;
+        assert_eq!(decode(&max).ok(), Some(u64::MAX));
+
+        max[9] = 0x02;
+        assert!(matches!(decode(&max), Err(DecodeError::Other(_))));
+
+        max[9] = 0x7F;
+        assert!(matches!(decode(&max), Err(DecodeError::Other(_))));
+
+        let mut long = [0xFF_u8; 11];
+        long[9] = 0x81;
+        long[10] = 0x00;
+        assert!(matches!(decode(&long), Err(DecodeError::Other(_))));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_wrapping_deltas() {
+        let mut encoder = CompactEncoder::<8>::new(0);
+        let mut decoder = CompactDecoder::<8>::new();
+
+        let value = reading();
+        let first = Version2DataFrame::new(u64::MAX, TimeBase::Gps, u32::MAX, 5, 1, value);
+        let second = Version2DataFrame::new(10, TimeBase::Gps, 0, 2, 1, value);
+
+        for frame in [first, second] {
+            let restored = decoder
+                .decode(encoder.encode(frame.clone()))
+                .expect("Failed to decode");
+            assert_eq!(restored, frame);
+        }
+    }
+}
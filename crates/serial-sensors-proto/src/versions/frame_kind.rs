@@ -0,0 +1,72 @@
+//! The kind of a protocol version 2 frame.
+
+use crate::MaxEncodedLen;
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+
+/// Describes the kind of data frame carried by a protocol version 2 frame.
+///
+/// The kind is encoded in the byte following the version byte, so that receivers can tell
+/// the frames of a mixed stream apart before decoding them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum FrameKind {
+    /// A single reading, see [`Version2DataFrame`](crate::versions::Version2DataFrame).
+    Single = 0x00,
+    /// Consecutive readings of a sensor, see [`BatchDataFrame`](crate::versions::BatchDataFrame).
+    Batch = 0x01,
+    /// A reading with a delta-coded header, see
+    /// [`CompactDataFrame`](crate::versions::CompactDataFrame).
+    Compact = 0x02,
+}
+
+impl FrameKind {
+    /// Decodes a frame kind and verifies that it matches this kind.
+    pub(crate) fn decode_expected<D: Decoder>(self, decoder: &mut D) -> Result<(), DecodeError> {
+        let kind: FrameKind = Decode::decode(decoder)?;
+        if kind == self {
+            Ok(())
+        } else {
+            Err(DecodeError::Other("Unexpected frame kind"))
+        }
+    }
+}
+
+impl TryFrom<u8> for FrameKind {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(FrameKind::Single),
+            0x01 => Ok(FrameKind::Batch),
+            0x02 => Ok(FrameKind::Compact),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Encode for FrameKind {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        Encode::encode(&(*self as u8), encoder)
+    }
+}
+
+impl Decode for FrameKind {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let value: u8 = Decode::decode(decoder)?;
+        FrameKind::try_from(value).map_err(|()| DecodeError::Other("Unknown frame kind"))
+    }
+}
+
+impl MaxEncodedLen for FrameKind {
+    const MAX_ENCODED_LEN: usize = u8::MAX_ENCODED_LEN;
+}
+
+impl<'a> BorrowDecode<'a> for FrameKind {
+    fn borrow_decode<D: BorrowDecoder<'a>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        FrameKind::decode(decoder)
+    }
+}
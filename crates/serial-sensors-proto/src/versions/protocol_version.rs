@@ -1,7 +1,7 @@
 use crate::{DataFrame, VersionedDataFrame};
 
 macro_rules! impl_version {
-    ($comment:literal, $type:tt, $version:literal) => {
+    ($comment:literal, $type:ident, $version:literal) => {
         #[doc = $comment]
         #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
         #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -22,7 +22,7 @@ macro_rules! impl_version {
 
         impl<D> From<D> for VersionedDataFrame<$type, D>
         where
-            D: DataFrame,
+            D: DataFrame<ProtocolVersion = $type>,
         {
             fn from(value: D) -> VersionedDataFrame<$type, D> {
                 $type::frame(value)
@@ -33,6 +33,10 @@ macro_rules! impl_version {
             const VERSION: usize = $version;
         }
 
+        impl $crate::MaxEncodedLen for $type {
+            const MAX_ENCODED_LEN: usize = 1;
+        }
+
         #[allow(unused_qualifications)]
         impl bincode::Encode for $type {
             fn encode<__E: bincode::enc::Encoder>(
@@ -44,20 +48,106 @@ macro_rules! impl_version {
             }
         }
 
+        impl TryFrom<u8> for $type {
+            type Error = VersionMismatch;
+
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                if value == $version {
+                    Ok(Self)
+                } else {
+                    Err(VersionMismatch {
+                        expected: $version,
+                        received: value,
+                    })
+                }
+            }
+        }
+
         #[allow(unused_qualifications)]
-        impl bincode::Decode for Version1 {
+        impl bincode::Decode for $type {
             fn decode<__D: bincode::de::Decoder>(
                 decoder: &mut __D,
             ) -> Result<Self, bincode::error::DecodeError> {
                 let v: u8 = bincode::Decode::decode(decoder)?;
-                if v != $version {
-                    Err(bincode::error::DecodeError::Other("invalid version"))
-                } else {
-                    Ok(Self)
-                }
+                Self::try_from(v).map_err(|e| bincode::error::DecodeError::UnexpectedVariant {
+                    type_name: stringify!($type),
+                    allowed: &bincode::error::AllowedEnumVariants::Allowed(&[$version]),
+                    found: u32::from(e.received),
+                })
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'de> bincode::BorrowDecode<'de> for $type {
+            fn borrow_decode<__D: bincode::de::BorrowDecoder<'de>>(
+                decoder: &mut __D,
+            ) -> Result<Self, bincode::error::DecodeError> {
+                bincode::Decode::decode(decoder)
             }
         }
     };
 }
 
+/// A frame was received with a protocol version other than the expected one.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VersionMismatch {
+    /// The expected version byte.
+    pub expected: u8,
+    /// The received version byte.
+    pub received: u8,
+}
+
+impl core::fmt::Display for VersionMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "protocol version mismatch: expected {}, received {}",
+            self.expected, self.received
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VersionMismatch {}
+
 impl_version!("Protocol version 1.", Version1, 1);
+impl_version!("Protocol version 2.", Version2, 2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serializer::SERIALIZATION_CONFIG;
+    use bincode::error::DecodeError;
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_decode_version() {
+        let (version, _): (Version2, _) =
+            bincode::decode_from_slice(&[2], SERIALIZATION_CONFIG).expect("Failed to decode");
+        assert_eq!(version, Version2);
+
+        let (version, _): (Version2, _) =
+            bincode::borrow_decode_from_slice(&[2], SERIALIZATION_CONFIG)
+                .expect("Failed to decode");
+        assert_eq!(version, Version2);
+    }
+
+    #[test]
+    fn test_version_mismatch() {
+        assert_eq!(
+            Version1::try_from(2),
+            Err(VersionMismatch {
+                expected: 1,
+                received: 2
+            })
+        );
+
+        let result: Result<(Version1, _), _> =
+            bincode::decode_from_slice(&[2], SERIALIZATION_CONFIG);
+        assert!(matches!(
+            result,
+            Err(DecodeError::UnexpectedVariant { found: 2, .. })
+        ));
+    }
+}
@@ -1,7 +1,9 @@
 //! A version 1 data frame.
 
 use crate::versions::Version1;
-use crate::{DataFrame, SensorData, SensorId};
+use crate::{
+    max_wire_len, Checksum, DataFrame, MaxEncodedLen, SensorData, SensorId, VersionedDataFrame,
+};
 use bincode::Encode;
 
 /// A sensor data frame.
@@ -66,7 +68,35 @@ impl DataFrame for Version1DataFrame {
     }
 }
 
+impl MaxEncodedLen for Version1DataFrame {
+    const MAX_ENCODED_LEN: usize = u32::MAX_ENCODED_LEN
+        + u16::MAX_ENCODED_LEN
+        + u16::MAX_ENCODED_LEN
+        + u32::MAX_ENCODED_LEN
+        + u32::MAX_ENCODED_LEN
+        + u16::MAX_ENCODED_LEN
+        + SensorData::MAX_ENCODED_LEN;
+}
+
 impl Version1DataFrame {
+    /// The maximum number of bytes of a serialized frame on the wire, accounting for the
+    /// version byte, the largest [`SensorData`] reading, a CRC-32 trailer, the byte stuffing
+    /// overhead and the delimiter.
+    ///
+    /// This is the size required for receive buffers and for [`serialize_to_writer`](crate::serialize_to_writer).
+    pub const MAX_WIRE_LEN: usize = max_wire_len(
+        VersionedDataFrame::<Version1, Self>::MAX_ENCODED_LEN,
+        Checksum::Crc32,
+    );
+
+    /// The buffer size required by [`serialize`](crate::serialize) and
+    /// [`serialize_with_checksum`](crate::serialize_with_checksum), which serialize the
+    /// frame into the front of the buffer before applying byte stuffing.
+    pub const MAX_SERIALIZE_BUFFER_LEN: usize =
+        VersionedDataFrame::<Version1, Self>::MAX_ENCODED_LEN
+            + Checksum::Crc32.len()
+            + Self::MAX_WIRE_LEN;
+
     /// Creates a new instance of the version 1 data frame.
     #[must_use]
     pub fn new<D>(
@@ -0,0 +1,381 @@
+//! A version 2 data frame.
+
+use crate::versions::{FrameKind, Version1, Version1DataFrame, Version2};
+use crate::{
+    max_wire_len, Checksum, DataFrame, MaxEncodedLen, SensorData, SensorId, VersionedDataFrame,
+};
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+
+/// The number of nanoseconds per second.
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+/// The number of nanoseconds per millisecond.
+const NANOS_PER_MILLI: u64 = 1_000_000;
+
+/// Describes the epoch a timestamp is relative to.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum TimeBase {
+    /// Time since startup of the device, or since begin of a measurement.
+    #[default]
+    BootRelative = 0x00,
+    /// Time since the Unix epoch (1970-01-01T00:00:00Z).
+    Unix = 0x01,
+    /// Time since the GPS epoch (1980-01-06T00:00:00Z), without leap seconds.
+    Gps = 0x02,
+}
+
+impl Encode for TimeBase {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        Encode::encode(&(*self as u8), encoder)
+    }
+}
+
+impl Decode for TimeBase {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let value: u8 = Decode::decode(decoder)?;
+        match value {
+            0x00 => Ok(TimeBase::BootRelative),
+            0x01 => Ok(TimeBase::Unix),
+            0x02 => Ok(TimeBase::Gps),
+            _ => Err(DecodeError::Other("Unknown time base")),
+        }
+    }
+}
+
+impl MaxEncodedLen for TimeBase {
+    const MAX_ENCODED_LEN: usize = u8::MAX_ENCODED_LEN;
+}
+
+impl<'a> BorrowDecode<'a> for TimeBase {
+    fn borrow_decode<D: BorrowDecoder<'a>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        TimeBase::decode(decoder)
+    }
+}
+
+/// A sensor data frame.
+///
+/// On the wire, the frame is tagged as [`FrameKind::Single`].
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Version2DataFrame {
+    /// A monotonic timestamp in nanoseconds, relative to the [`time_base`](Self::time_base).
+    ///
+    /// If unsupported, set to [`u64::MAX`].
+    pub timestamp_nanos: u64,
+
+    /// The epoch the timestamp is relative to.
+    pub time_base: TimeBase,
+
+    /// A sequence identifier, monotonically increasing.
+    ///
+    /// This value can be used to detect package loss on the receiver side. It should increase
+    /// on every transmitted package, across all sensor.
+    ///
+    /// If unsupported, set to [`u32::MAX`].
+    pub global_sequence: u32,
+
+    /// A sensor sequence identifier, monotonically increasing.
+    ///
+    /// This value should increase whenever new data became available for the specific
+    /// sensor, not when it was actually transmitted.
+    ///
+    /// If unsupported, set to [`u32::MAX`].
+    pub sensor_sequence: u32,
+
+    /// A device-specific tag for a specific sensor.
+    ///
+    /// This value should be identical across all readings from the same sensor. This
+    /// is to ensure that multiple sensors of the same type, e.g. multiple accelerometers,
+    /// can be told apart on the host side.
+    pub sensor_tag: u16,
+
+    /// The sensor reading.
+    pub value: SensorData,
+}
+
+impl DataFrame for Version2DataFrame {
+    type ProtocolVersion = Version2;
+
+    fn is_meta(&self) -> bool {
+        self.value.is_meta()
+    }
+
+    fn target(&self) -> SensorId {
+        self.value
+            .meta_target()
+            .cloned()
+            .unwrap_or_else(|| self.into())
+    }
+}
+
+impl MaxEncodedLen for Version2DataFrame {
+    const MAX_ENCODED_LEN: usize = FrameKind::MAX_ENCODED_LEN
+        + u64::MAX_ENCODED_LEN
+        + TimeBase::MAX_ENCODED_LEN
+        + u32::MAX_ENCODED_LEN
+        + u32::MAX_ENCODED_LEN
+        + u16::MAX_ENCODED_LEN
+        + SensorData::MAX_ENCODED_LEN;
+}
+
+impl Version2DataFrame {
+    /// The maximum number of bytes of a serialized frame on the wire, accounting for the
+    /// version byte, the largest [`SensorData`] reading, a CRC-32 trailer, the byte stuffing
+    /// overhead and the delimiter.
+    ///
+    /// This is the size required for receive buffers and for [`serialize_to_writer`](crate::serialize_to_writer).
+    pub const MAX_WIRE_LEN: usize = max_wire_len(
+        VersionedDataFrame::<Version2, Self>::MAX_ENCODED_LEN,
+        Checksum::Crc32,
+    );
+
+    /// The buffer size required by [`serialize`](crate::serialize) and
+    /// [`serialize_with_checksum`](crate::serialize_with_checksum), which serialize the
+    /// frame into the front of the buffer before applying byte stuffing.
+    pub const MAX_SERIALIZE_BUFFER_LEN: usize =
+        VersionedDataFrame::<Version2, Self>::MAX_ENCODED_LEN
+            + Checksum::Crc32.len()
+            + Self::MAX_WIRE_LEN;
+
+    /// Creates a new instance of the version 2 data frame.
+    #[must_use]
+    pub fn new<D>(
+        timestamp_nanos: u64,
+        time_base: TimeBase,
+        global_sequence: u32,
+        sensor_sequence: u32,
+        sensor_tag: u16,
+        value: D,
+    ) -> Self
+    where
+        D: Into<SensorData>,
+    {
+        Self::new_with(
+            timestamp_nanos,
+            time_base,
+            global_sequence,
+            sensor_sequence,
+            sensor_tag,
+            value.into(),
+        )
+    }
+
+    /// Creates a new instance of the version 2 data frame.
+    #[must_use]
+    pub const fn new_with(
+        timestamp_nanos: u64,
+        time_base: TimeBase,
+        global_sequence: u32,
+        sensor_sequence: u32,
+        sensor_tag: u16,
+        value: SensorData,
+    ) -> Self {
+        Self {
+            timestamp_nanos,
+            time_base,
+            global_sequence,
+            sensor_sequence,
+            sensor_tag,
+            value,
+        }
+    }
+}
+
+impl Encode for Version2DataFrame {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        Encode::encode(&FrameKind::Single, encoder)?;
+        Encode::encode(&self.timestamp_nanos, encoder)?;
+        Encode::encode(&self.time_base, encoder)?;
+        Encode::encode(&self.global_sequence, encoder)?;
+        Encode::encode(&self.sensor_sequence, encoder)?;
+        Encode::encode(&self.sensor_tag, encoder)?;
+        Encode::encode(&self.value, encoder)
+    }
+}
+
+impl Decode for Version2DataFrame {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        FrameKind::Single.decode_expected(decoder)?;
+        Ok(Self {
+            timestamp_nanos: Decode::decode(decoder)?,
+            time_base: Decode::decode(decoder)?,
+            global_sequence: Decode::decode(decoder)?,
+            sensor_sequence: Decode::decode(decoder)?,
+            sensor_tag: Decode::decode(decoder)?,
+            value: Decode::decode(decoder)?,
+        })
+    }
+}
+
+/// Converts a version 1 frame, interpreting its time as [`TimeBase::BootRelative`].
+///
+/// Unsupported millisecond or nanosecond fields are treated as zero; if the seconds
+/// are unsupported, so is the resulting timestamp.
+impl From<Version1DataFrame> for Version2DataFrame {
+    fn from(value: Version1DataFrame) -> Self {
+        let timestamp_nanos = if value.system_secs == u32::MAX {
+            u64::MAX
+        } else {
+            let millis = if value.system_millis == u16::MAX {
+                0
+            } else {
+                u64::from(value.system_millis)
+            };
+            let nanos = if value.system_nanos == u16::MAX {
+                0
+            } else {
+                u64::from(value.system_nanos)
+            };
+            u64::from(value.system_secs) * NANOS_PER_SEC + millis * NANOS_PER_MILLI + nanos
+        };
+
+        Self {
+            timestamp_nanos,
+            time_base: TimeBase::BootRelative,
+            global_sequence: value.global_sequence,
+            sensor_sequence: value.sensor_sequence,
+            sensor_tag: value.sensor_tag,
+            value: value.value,
+        }
+    }
+}
+
+/// Converts a version 2 frame, discarding its [`TimeBase`].
+///
+/// Version 1 frames can only carry up to 65534 sub-millisecond nanoseconds; larger values
+/// are saturated. The conversion fails if the seconds do not fit into a [`u32`].
+impl TryFrom<Version2DataFrame> for Version1DataFrame {
+    type Error = ();
+
+    fn try_from(value: Version2DataFrame) -> Result<Self, Self::Error> {
+        let (system_secs, system_millis, system_nanos) = if value.timestamp_nanos == u64::MAX {
+            (u32::MAX, u16::MAX, u16::MAX)
+        } else {
+            let secs = u32::try_from(value.timestamp_nanos / NANOS_PER_SEC).map_err(|_| ())?;
+            let subsec_nanos = value.timestamp_nanos % NANOS_PER_SEC;
+            let millis = u16::try_from(subsec_nanos / NANOS_PER_MILLI).map_err(|_| ())?;
+            let nanos = u16::try_from(subsec_nanos % NANOS_PER_MILLI)
+                .unwrap_or(u16::MAX)
+                .min(u16::MAX - 1);
+            (secs, millis, nanos)
+        };
+
+        Ok(Version1DataFrame::new_with(
+            system_secs,
+            system_millis,
+            system_nanos,
+            value.global_sequence,
+            value.sensor_sequence,
+            value.sensor_tag,
+            value.value,
+        ))
+    }
+}
+
+impl From<VersionedDataFrame<Version1, Version1DataFrame>>
+    for VersionedDataFrame<Version2, Version2DataFrame>
+{
+    fn from(value: VersionedDataFrame<Version1, Version1DataFrame>) -> Self {
+        Version2::frame(value.data.into())
+    }
+}
+
+impl TryFrom<VersionedDataFrame<Version2, Version2DataFrame>>
+    for VersionedDataFrame<Version1, Version1DataFrame>
+{
+    type Error = ();
+
+    fn try_from(value: VersionedDataFrame<Version2, Version2DataFrame>) -> Result<Self, ()> {
+        Ok(Version1::frame(value.data.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serializer::SERIALIZATION_CONFIG;
+    use crate::types::AccelerometerI16;
+    use crate::Vector3Data;
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_serialization() {
+        let frame = Version2DataFrame::new(
+            1_234_567_890_123,
+            TimeBase::Unix,
+            u32::MAX,
+            12,
+            0,
+            AccelerometerI16::new(Vector3Data { x: 0, y: -1, z: 2 }),
+        );
+
+        let mut buffer = [0_u8; 1024];
+        let num_serialized = bincode::encode_into_slice(
+            frame.clone().into_versioned(),
+            &mut buffer,
+            SERIALIZATION_CONFIG,
+        )
+        .expect("Failed to encode");
+        assert_eq!(
+            num_serialized,
+            1 // version
+                + 1 // frame kind
+                + 8 // time
+                + 1 // time base
+                + 4 // global sequence
+                + 4 // sensor sequence
+                + 2 // sensor tag
+                + 1 // sensor type
+                + 1 // data type
+                + 3 * 2 // 3-axis data
+        );
+        assert_eq!(buffer[..2], [2, FrameKind::Single as u8]);
+
+        let (value, num_read) =
+            bincode::decode_from_slice(&buffer, SERIALIZATION_CONFIG).expect("Failed to decode");
+        let value: VersionedDataFrame<Version2, Version2DataFrame> = value;
+        assert_eq!(num_read, num_serialized);
+        assert_eq!(value.version, Version2);
+        assert_eq!(value.data, frame);
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_version1_round_trip() {
+        let value = AccelerometerI16::new(Vector3Data { x: 0, y: -1, z: 2 });
+        let v1 = Version1DataFrame::new(12, 345, 678, 1, 2, 3, value);
+
+        let v2 = Version2DataFrame::from(v1.clone());
+        assert_eq!(v2.timestamp_nanos, 12_345_000_678);
+        assert_eq!(v2.time_base, TimeBase::BootRelative);
+
+        let back = Version1DataFrame::try_from(v2).expect("Failed to convert");
+        assert_eq!(back, v1);
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_version1_conversion_limits() {
+        let value = AccelerometerI16::new(Vector3Data { x: 0, y: -1, z: 2 });
+        let unsupported = Version1DataFrame::new(u32::MAX, u16::MAX, u16::MAX, 1, 2, 3, value);
+        let v2 = Version2DataFrame::from(unsupported.clone());
+        assert_eq!(v2.timestamp_nanos, u64::MAX);
+        assert_eq!(
+            Version1DataFrame::try_from(v2).expect("Failed to convert"),
+            unsupported
+        );
+
+        let v2 = Version2DataFrame::new(999_999, TimeBase::Gps, 1, 2, 3, value);
+        let v1 = Version1DataFrame::try_from(v2).expect("Failed to convert");
+        assert_eq!(v1.system_nanos, u16::MAX - 1);
+
+        let v2 = Version2DataFrame::new(u64::MAX - 1, TimeBase::Unix, 1, 2, 3, value);
+        assert!(Version1DataFrame::try_from(v2).is_err());
+    }
+}
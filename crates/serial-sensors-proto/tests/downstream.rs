@@ -0,0 +1,107 @@
+//! Ensures the derive macros can be used from outside of this crate.
+
+use bincode::config::{Configuration, Fixint, LittleEndian};
+use bincode::{Decode, Encode};
+use serial_sensors_proto::types::AccelerometerI16;
+use serial_sensors_proto::{
+    CompileTimeTypeInformation, ComponentLookupError, Components, MaxEncodedLen,
+    RuntimeTypeInformation, ScalarData, SensorDataType, SerialSensors, ValueType, Vector3Data,
+};
+
+const CONFIG: Configuration<LittleEndian, Fixint> = bincode::config::standard()
+    .with_fixed_int_encoding()
+    .with_little_endian();
+
+mod reexport {
+    pub use serial_sensors_proto as proto;
+}
+
+/// Wind speed in cm/s, 1×`u16`.
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq)]
+#[sensor(skip_frame_conversions)]
+pub struct WindSpeedU16(ScalarData<u16>);
+
+/// Wind direction in degrees, 1×`u16`.
+#[derive(SensorDataType, Encode, Decode, Debug, Default, Copy, Clone, PartialEq)]
+#[sensor(crate = reexport::proto, skip_frame_conversions)]
+pub struct WindDirectionU16(ScalarData<u16>);
+
+/// A vendor-specific set of sensors.
+#[derive(Debug, Clone, PartialEq, SerialSensors)]
+pub enum VendorSensorData {
+    /// A standard accelerometer.
+    #[sensor(id = 0x42, data = ValueType::SInt16, components = 3, skip_type_info)]
+    AccelerometerI16(AccelerometerI16),
+
+    /// A vendor-specific wind speed sensor.
+    #[sensor(id = 0x80, data = ValueType::UInt16, components = 1)]
+    WindSpeedU16(WindSpeedU16),
+
+    /// A vendor-specific wind direction sensor.
+    #[sensor(id = 0x81, data = ValueType::UInt16, components = 1)]
+    WindDirectionU16(WindDirectionU16),
+}
+
+mod reexported {
+    use super::{reexport, WindSpeedU16};
+    use serial_sensors_proto::{SerialSensors, ValueType};
+
+    /// A vendor-specific set of sensors using an explicit crate path.
+    #[derive(Debug, Clone, PartialEq, SerialSensors)]
+    #[sensor(crate = reexport::proto)]
+    pub enum ReexportedSensorData {
+        /// A vendor-specific wind speed sensor.
+        #[sensor(id = 0x80, data = ValueType::UInt16, components = 1, skip_type_info)]
+        WindSpeedU16(WindSpeedU16),
+    }
+}
+
+#[test]
+fn test_type_information() {
+    assert_eq!(WindSpeedU16::TYPE_ID, 0x80);
+    assert_eq!(WindDirectionU16::VALUE_TYPE, ValueType::UInt16);
+
+    let value: VendorSensorData = WindSpeedU16::new(ScalarData::new(1234)).into();
+    assert_eq!(value.sensor_type_id(), 0x80);
+    assert_eq!(
+        RuntimeTypeInformation::value_type(&value),
+        ValueType::UInt16
+    );
+    assert_eq!(VendorSensorData::components(0x81, ValueType::UInt16), Ok(1));
+    assert_eq!(
+        VendorSensorData::components(0x81, ValueType::SInt16),
+        Err(ComponentLookupError::UnknownType)
+    );
+    assert_eq!(SensorIds::WINDSPEEDU16.id(), 0x80);
+
+    let value: reexported::ReexportedSensorData = WindSpeedU16::new(ScalarData::new(1)).into();
+    assert_eq!(value.num_components(), 1);
+    assert_eq!(value.component(0), Some(1.0));
+    assert_eq!(value.component(1), None);
+
+    assert_eq!(WindSpeedU16::MAX_ENCODED_LEN, 2);
+    assert_eq!(VendorSensorData::MAX_ENCODED_LEN, 2 + 6);
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+fn test_round_trip() {
+    let mut buffer = [0_u8; 64];
+    for value in [
+        VendorSensorData::from(AccelerometerI16::new(Vector3Data::new(1, -2, 3))),
+        VendorSensorData::from(WindSpeedU16::new(ScalarData::new(1234))),
+        VendorSensorData::from(WindDirectionU16::new(ScalarData::new(270))),
+    ] {
+        let num_serialized = bincode::encode_into_slice(value.clone(), &mut buffer, CONFIG)
+            .expect("Failed to serialize");
+        let (decoded, num_read): (VendorSensorData, _) =
+            bincode::decode_from_slice(&buffer, CONFIG).expect("Failed to deserialize");
+        assert_eq!(num_read, num_serialized);
+        assert_eq!(decoded, value);
+    }
+
+    let speed: WindSpeedU16 = VendorSensorData::from(WindSpeedU16::new(ScalarData::new(5)))
+        .try_into()
+        .expect("Failed to convert");
+    assert_eq!(speed.value, 5);
+}